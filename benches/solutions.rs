@@ -0,0 +1,41 @@
+use advent_of_code_2023::{input::get_input, solutions::{Day, Part, YEARS}};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Benchmarks a registered day, timing parsing and each part's solving separately from the
+/// timings `Day::run` reports, so every day in the registry is covered.
+fn bench_day(c: &mut Criterion, day: &Day) {
+    let Ok(input) = get_input(day.year, day.name) else {
+        return eprintln!("skipping {} {}, no input", day.year, day.name);
+    };
+
+    let mut group = c.benchmark_group(format!("{}/{}", day.year, day.name));
+
+    // (benchmark name, part to run, whether to time parsing rather than solving)
+    let phases = [("parse", Part::One, true), ("part one", Part::One, false), ("part two", Part::Two, false)];
+
+    for (name, part, parse) in phases {
+        group.bench_function(name, |b| b.iter_custom(|iters| {
+            (0..iters)
+                .map(|_| {
+                    let timed = (day.run)(&input, part);
+                    if parse { timed.parse } else { timed.solve }
+                })
+                .sum()
+        }));
+    }
+
+    group.finish();
+}
+
+fn solutions(c: &mut Criterion) {
+    for day in YEARS.iter().flat_map(|days| days.iter()) {
+        bench_day(c, day);
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = solutions
+}
+criterion_main!(benches);
@@ -0,0 +1,107 @@
+use std::{collections::HashMap, fmt, fs::{create_dir_all, read_to_string, write}, path::PathBuf};
+
+use crate::{config, solutions::{Answer, Part}};
+
+fn path(year: &str, day: &str) -> PathBuf {
+    config::get().path("answers").join(year).join(format!("{day}.txt"))
+}
+
+pub enum Check {
+    Match,
+    Mismatch(String),
+    Unrecorded,
+}
+
+fn load(year: &str, day: &str) -> HashMap<Part, String> {
+    read_to_string(path(year, day)).unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            let (part, answer) = line.split_once(": ")?;
+            Some((part.parse().ok()?, answer.to_string()))
+        })
+        .collect()
+}
+
+pub fn record(year: &str, day: &str, part: Part, answer: &Answer) {
+    let mut answers = load(year, day);
+    answers.insert(part, answer.to_string());
+
+    let contents = [Part::One, Part::Two].into_iter()
+        .filter_map(|part| answers.get(&part).map(|answer| format!("{part}: {answer}\n")))
+        .collect::<String>();
+
+    let path = path(year, day);
+    create_dir_all(path.parent().unwrap()).unwrap();
+    write(path, contents).unwrap();
+}
+
+pub fn check(year: &str, day: &str, part: Part, answer: &Answer) -> Check {
+    match load(year, day).remove(&part) {
+        Some(expected) if expected == answer.to_string() => Check::Match,
+        Some(expected) => Check::Mismatch(expected),
+        None => Check::Unrecorded,
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Mode {
+    Record,
+    Check,
+}
+
+/// Records or checks an answer, returning false if it doesn't match the recorded one.
+pub fn verify(mode: Mode, year: &str, day: &str, part: Part, answer: &Answer) -> bool {
+    match mode {
+        Mode::Record => {
+            record(year, day, part, answer);
+            true
+        },
+        Mode::Check => match check(year, day, part, answer) {
+            Check::Match => true,
+            Check::Mismatch(expected) => {
+                eprintln!("{year} {day} part {part}: got {answer}, expected {expected}");
+                false
+            },
+            Check::Unrecorded => {
+                eprintln!("{year} {day} part {part}: no recorded answer");
+                true
+            },
+        },
+    }
+}
+
+/// Reports a part that gave no answer, returning false if there's a recorded answer it should
+/// have matched.
+pub fn verify_unsolved(mode: Mode, year: &str, day: &str, part: Part, reason: &impl fmt::Display) -> bool {
+    let expected = match mode {
+        Mode::Record => None,
+        Mode::Check => load(year, day).remove(&part),
+    };
+
+    if unsolved_passes(mode, expected.as_deref()) {
+        return true;
+    }
+
+    eprintln!("{year} {day} part {part}: {reason}, expected {}", expected.unwrap_or_default());
+    false
+}
+
+/// Recording skips parts without an answer, but checking fails them if one was recorded.
+fn unsolved_passes(mode: Mode, expected: Option<&str>) -> bool {
+    match mode {
+        Mode::Record => true,
+        Mode::Check => expected.is_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsolved_parts() {
+        assert!(unsolved_passes(Mode::Record, Some("142")));
+        assert!(unsolved_passes(Mode::Check, None));
+        assert!(!unsolved_passes(Mode::Check, Some("142")));
+    }
+}
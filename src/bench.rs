@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use std::{fs::{create_dir_all, read_to_string, write}, time::Duration};
+
+use crate::{config, input::{get_input, InputError}, solutions::{Day, Part}};
+
+/// Summary statistics of a set of timings, in nanoseconds.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Stats {
+    min: f64,
+    median: f64,
+    mean: f64,
+    std_dev: f64,
+}
+impl Stats {
+    fn new(timings: &[Duration]) -> Stats {
+        let mut nanos = timings.iter().map(|t| t.as_nanos() as f64).collect::<Vec<f64>>();
+        nanos.sort_by(f64::total_cmp);
+
+        let len = nanos.len() as f64;
+        let mid = nanos.len() / 2;
+
+        let median = if nanos.len() % 2 == 0 {
+            (nanos[mid - 1] + nanos[mid]) / 2.0
+        } else {
+            nanos[mid]
+        };
+        let mean = nanos.iter().sum::<f64>() / len;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / len;
+
+        Stats {
+            min: nanos[0],
+            median,
+            mean,
+            std_dev: variance.sqrt(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Report {
+    year: String,
+    day: String,
+    part: Part,
+    runs: usize,
+    parse: Stats,
+    solve: Stats,
+}
+
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos as u64))
+}
+
+/// Times parsing and solving separately over `runs` iterations, warning if either median is
+/// more than `threshold` percent slower than the saved baseline. The results only replace the
+/// baseline when `save_baseline` is set, so one slow run can't hide a regression.
+pub fn bench(day: &Day, part: Part, runs: usize, threshold: f64, save_baseline: bool) -> Result<(), InputError> {
+    let input = get_input(day.year, day.name)?;
+
+    let (parse, solve): (Vec<Duration>, Vec<Duration>) = (0..runs.max(1))
+        .map(|_| {
+            let timed = (day.run)(&input, part);
+            (timed.parse, timed.solve)
+        })
+        .unzip();
+
+    let report = Report {
+        year: day.year.to_string(),
+        day: day.name.to_string(),
+        part,
+        runs: parse.len(),
+        parse: Stats::new(&parse),
+        solve: Stats::new(&solve),
+    };
+
+    println!("{} {} part {part} ({} runs)", report.year, report.day, report.runs);
+    println!("{:<8}{:>12}{:>12}{:>12}{:>12}", "", "min", "median", "mean", "std dev");
+    for (label, stats) in [("parse", &report.parse), ("solve", &report.solve)] {
+        println!(
+            "{:<8}{:>12}{:>12}{:>12}{:>12}",
+            label,
+            format_nanos(stats.min),
+            format_nanos(stats.median),
+            format_nanos(stats.mean),
+            format_nanos(stats.std_dev),
+        );
+    }
+
+    let dir = config::get().path("benchmarks").join(day.year);
+    let path = dir.join(format!("{}_{part}.json", day.name));
+
+    let baseline = read_to_string(&path).ok()
+        .and_then(|json| serde_json::from_str::<Report>(&json).ok());
+
+    if let Some(baseline) = &baseline {
+        for (label, old, new) in [
+            ("parse", &baseline.parse, &report.parse),
+            ("solve", &baseline.solve, &report.solve),
+        ] {
+            let change = (new.median - old.median) / old.median * 100.0;
+
+            if change > threshold {
+                println!(
+                    "warning: {label} median regressed by {change:.1}% ({} -> {})",
+                    format_nanos(old.median),
+                    format_nanos(new.median),
+                );
+            }
+        }
+    }
+
+    if save_baseline {
+        create_dir_all(dir)?;
+        write(&path, serde_json::to_string_pretty(&report).unwrap())?;
+        println!("saved baseline to {}", path.display());
+    } else if baseline.is_none() {
+        println!("no baseline to compare against, pass --save-baseline to save one");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let timings = [4, 1, 3, 2, 5].map(Duration::from_nanos);
+        let stats = Stats::new(&timings);
+
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 3.0);
+        assert_eq!(stats.mean, 3.0);
+        assert_eq!(stats.std_dev, 2.0_f64.sqrt());
+    }
+}
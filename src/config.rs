@@ -0,0 +1,242 @@
+use serde::Deserialize;
+use std::{env, fs::read_to_string, path::{Path, PathBuf}, str::FromStr, sync::OnceLock, time::Duration};
+
+use crate::{input::URL, solutions::Part};
+
+const FILE_NAME: &str = "aoc.toml";
+const USER_AGENT: &str = "https://github.com/Ben-Wormald/advent-of-code-2023";
+const REQUEST_INTERVAL: u64 = 5;
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON object per line.
+    Json,
+}
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Format, String> {
+        <Format as clap::ValueEnum>::from_str(value, true)
+    }
+}
+
+/// Settings as written in `aoc.toml`, where every field is optional.
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FileConfig {
+    pub session_cookie: Option<String>,
+    pub input_dir: Option<PathBuf>,
+    pub cache_dir: Option<PathBuf>,
+    pub year: Option<String>,
+    pub user_agent: Option<String>,
+    pub part: Option<String>,
+    pub format: Option<Format>,
+    pub base_url: Option<String>,
+    pub offline: Option<bool>,
+    /// Seconds to wait between requests.
+    pub request_interval: Option<u64>,
+}
+
+/// Settings given on the command line, which take precedence over everything else.
+#[derive(Debug, Default)]
+pub struct Overrides {
+    pub input_dir: Option<PathBuf>,
+    pub cache_dir: Option<PathBuf>,
+    pub year: Option<String>,
+    pub user_agent: Option<String>,
+    pub part: Option<Part>,
+    pub format: Option<Format>,
+    pub base_url: Option<String>,
+    pub offline: bool,
+}
+
+#[derive(Debug)]
+pub struct Config {
+    /// The directory `aoc.toml` was found in, which relative paths are resolved against.
+    pub root: PathBuf,
+    pub session_cookie: Option<String>,
+    pub input_dir: PathBuf,
+    pub cache_dir: PathBuf,
+    pub year: Option<String>,
+    pub user_agent: String,
+    pub part: Option<Part>,
+    pub format: Format,
+    /// The puzzle site, which can be pointed at a local server for testing.
+    pub base_url: String,
+    /// Whether to fail rather than fetch anything that isn't cached.
+    pub offline: bool,
+    /// The shortest time to leave between requests to the puzzle site.
+    pub request_interval: Duration,
+}
+impl Config {
+    /// Merges the settings with precedence CLI > environment > file > defaults.
+    pub fn resolve(
+        root: PathBuf,
+        file: FileConfig,
+        env: impl Fn(&str) -> Option<String>,
+        cli: Overrides,
+    ) -> Result<Config, String> {
+        let part = match cli.part {
+            Some(part) => Some(part),
+            None => env("AOC_PART").or(file.part).map(|part| part.parse()).transpose()?,
+        };
+        let format = match cli.format {
+            Some(format) => format,
+            None => match env("AOC_FORMAT") {
+                Some(format) => format.parse()?,
+                None => file.format.unwrap_or_default(),
+            },
+        };
+
+        let input_dir = cli.input_dir
+            .or(env("AOC_INPUT_DIR").map(PathBuf::from))
+            .or(file.input_dir)
+            .unwrap_or_else(|| PathBuf::from("input"));
+        let cache_dir = cli.cache_dir
+            .or(env("AOC_CACHE_DIR").map(PathBuf::from))
+            .or(file.cache_dir)
+            .unwrap_or_else(|| PathBuf::from(".cache"));
+
+        Ok(Config {
+            session_cookie: env("SESSION_COOKIE").or(file.session_cookie),
+            input_dir: root.join(input_dir),
+            cache_dir: root.join(cache_dir),
+            year: cli.year.or(env("AOC_YEAR")).or(file.year),
+            user_agent: cli.user_agent
+                .or(env("AOC_USER_AGENT"))
+                .or(file.user_agent)
+                .unwrap_or_else(|| USER_AGENT.to_string()),
+            part,
+            format,
+            base_url: cli.base_url
+                .or(env("AOC_BASE_URL"))
+                .or(file.base_url)
+                .map(|url| url.trim_end_matches('/').to_string())
+                .unwrap_or_else(|| URL.to_string()),
+            offline: cli.offline || file.offline.unwrap_or_default(),
+            request_interval: Duration::from_secs(file.request_interval.unwrap_or(REQUEST_INTERVAL)),
+            root,
+        })
+    }
+
+    /// Resolves a path relative to the project root.
+    pub fn path(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.root.join(path)
+    }
+}
+
+/// Looks for `aoc.toml` in the current directory and its parents.
+fn find_file() -> Option<PathBuf> {
+    let cwd = env::current_dir().ok()?;
+
+    cwd.ancestors()
+        .map(|dir| dir.join(FILE_NAME))
+        .find(|path| path.is_file())
+}
+
+/// Loads the config file, if there is one, and sets the config for the rest of the run.
+pub fn init(cli: Overrides) -> Result<&'static Config, String> {
+    let (root, file) = match find_file() {
+        Some(path) => {
+            let contents = read_to_string(&path).map_err(|error| format!("{}: {error}", path.display()))?;
+            let file = toml::from_str(&contents).map_err(|error| format!("{}: {error}", path.display()))?;
+            (path.parent().unwrap().to_path_buf(), file)
+        },
+        None => (PathBuf::from("."), FileConfig::default()),
+    };
+
+    let config = Config::resolve(root, file, |key| env::var(key).ok(), cli)?;
+
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+/// Returns the config, falling back to the defaults if it hasn't been set.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(|| {
+        Config::resolve(PathBuf::from("."), FileConfig::default(), |_| None, Overrides::default()).unwrap()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_file() {
+        let file = "\
+            session_cookie = \"abc\"\n\
+            input_dir = \"puzzle-inputs\"\n\
+            year = \"2022\"\n\
+            part = \"two\"\n\
+            format = \"text\"\n\
+            base_url = \"http://localhost:8000/\"\n\
+            offline = true\n\
+        ";
+
+        let expected = FileConfig {
+            session_cookie: Some(String::from("abc")),
+            input_dir: Some(PathBuf::from("puzzle-inputs")),
+            year: Some(String::from("2022")),
+            part: Some(String::from("two")),
+            format: Some(Format::Text),
+            base_url: Some(String::from("http://localhost:8000/")),
+            offline: Some(true),
+            ..FileConfig::default()
+        };
+
+        assert_eq!(toml::from_str::<FileConfig>(file).unwrap(), expected);
+        assert!(toml::from_str::<FileConfig>("unknown = 1").is_err());
+    }
+
+    #[test]
+    fn precedence() {
+        let file = FileConfig {
+            input_dir: Some(PathBuf::from("from-file")),
+            cache_dir: Some(PathBuf::from("from-file")),
+            year: Some(String::from("2020")),
+            user_agent: Some(String::from("from-file")),
+            part: Some(String::from("1")),
+            base_url: Some(String::from("http://localhost:8000/")),
+            ..FileConfig::default()
+        };
+        let env = |key: &str| match key {
+            "AOC_YEAR" => Some(String::from("2021")),
+            "AOC_CACHE_DIR" => Some(String::from("from-env")),
+            "AOC_PART" => Some(String::from("2")),
+            _ => None,
+        };
+        let cli = Overrides {
+            year: Some(String::from("2022")),
+            ..Overrides::default()
+        };
+
+        let config = Config::resolve(PathBuf::from("/project"), file, env, cli).unwrap();
+
+        assert_eq!(config.year.as_deref(), Some("2022"));
+        assert_eq!(config.part, Some(Part::Two));
+        assert_eq!(config.cache_dir, PathBuf::from("/project/from-env"));
+        assert_eq!(config.input_dir, PathBuf::from("/project/from-file"));
+        assert_eq!(config.user_agent, "from-file");
+        assert_eq!(config.format, Format::Text);
+        assert_eq!(config.base_url, "http://localhost:8000");
+    }
+
+    #[test]
+    fn defaults() {
+        let config = Config::resolve(PathBuf::from("/project"), FileConfig::default(), |_| None, Overrides::default()).unwrap();
+
+        assert_eq!(config.input_dir, PathBuf::from("/project/input"));
+        assert_eq!(config.cache_dir, PathBuf::from("/project/.cache"));
+        assert_eq!(config.year, None);
+        assert_eq!(config.user_agent, USER_AGENT);
+        assert_eq!(config.base_url, URL);
+        assert!(!config.offline);
+        assert_eq!(config.request_interval, Duration::from_secs(REQUEST_INTERVAL));
+        assert_eq!(config.path("answers"), PathBuf::from("/project/answers"));
+    }
+}
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+use std::fs::{create_dir_all, read_to_string, write};
+
+use crate::{config, markdown::{articles, strip_tags}, solutions::Part};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Example {
+    pub part: Part,
+    pub file: String,
+    pub expected: String,
+    /// Every code block in the part's description, saved so `file` can be pointed at another
+    /// if the wrong one was picked.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<String>,
+}
+
+/// Returns the contents of every `<pre><code>` block in some HTML, along with where the
+/// block ends.
+fn code_blocks(html: &str) -> Vec<(String, usize)> {
+    let mut blocks = Vec::new();
+    let mut offset = 0;
+
+    while let Some(start) = html[offset..].find("<pre><code>") {
+        let content_start = offset + start + "<pre><code>".len();
+        let Some(end) = html[content_start..].find("</code></pre>") else { break };
+
+        offset = content_start + end + "</code></pre>".len();
+        blocks.push((strip_tags(&html[content_start..content_start + end]), offset));
+    }
+
+    blocks
+}
+
+/// Returns the first emphasised code in some HTML, which is how puzzles give example answers.
+fn first_answer(html: &str) -> Option<String> {
+    html.split_once("<code><em>")
+        .and_then(|(_, rest)| rest.split_once("</em></code>"))
+        .map(|(answer, _)| strip_tags(answer))
+}
+
+/// Picks a part's example input and its answer. Descriptions often follow the input with
+/// diagrams worked from it, so this takes the first block after "For example", or the first
+/// block if there's no such phrase, and the first answer given after it.
+fn pick_example(article: &str) -> Option<(String, String)> {
+    let blocks = code_blocks(article);
+    let after = article.find("For example").unwrap_or(0);
+
+    let (input, end) = blocks.iter()
+        .find(|(_, end)| *end > after)
+        .or(blocks.first())?;
+
+    Some((input.clone(), first_answer(&article[*end..])?))
+}
+
+/// Finds each part's example and expected answer on a puzzle page. Part two falls back to
+/// the part one example when it doesn't give its own.
+pub fn extract(html: &str) -> Vec<(Part, String, String)> {
+    let mut examples = Vec::new();
+    let mut previous_input = None;
+
+    for (part, article) in [Part::One, Part::Two].into_iter().zip(articles(html)) {
+        let picked = match pick_example(article) {
+            Some(example) => Some(example),
+            None if code_blocks(article).is_empty() => previous_input.clone()
+                .zip(first_answer(article)),
+            None => None,
+        };
+
+        if let Some((input, expected)) = picked {
+            previous_input = Some(input.clone());
+            examples.push((part, input, expected));
+        }
+    }
+
+    examples
+}
+
+/// Writes each example to `examples/<year>/day_NN/` along with a manifest of expected answers,
+/// and every code block alongside in case a different one should be used.
+pub fn save(year: &str, day: &str, html: &str) -> Vec<Example> {
+    let dir = config::get().path("examples").join(year).join(day);
+    create_dir_all(&dir).unwrap();
+
+    let blocks = articles(html).into_iter().map(code_blocks).collect::<Vec<_>>();
+
+    let manifest = extract(html).into_iter().map(|(part, input, expected)| {
+        let file = format!("part_{part}.txt");
+        write(dir.join(&file), input).unwrap();
+
+        let index = match part {
+            Part::One => 0,
+            Part::Two => 1,
+        };
+        let blocks = blocks.get(index).into_iter().flatten().enumerate().map(|(n, (block, _))| {
+            let file = format!("part_{part}_block_{}.txt", n + 1);
+            write(dir.join(&file), block).unwrap();
+            file
+        }).collect();
+
+        Example {
+            part,
+            file,
+            expected,
+            blocks,
+        }
+    }).collect::<Vec<Example>>();
+
+    write(dir.join("manifest.json"), serde_json::to_string_pretty(&manifest).unwrap()).unwrap();
+
+    manifest
+}
+
+/// Returns each saved example's part, input and expected answer.
+pub fn load(year: &str, day: &str) -> Vec<(Part, String, String)> {
+    let dir = config::get().path("examples").join(year).join(day);
+
+    let Ok(manifest) = read_to_string(dir.join("manifest.json")) else {
+        return Vec::new();
+    };
+
+    serde_json::from_str::<Vec<Example>>(&manifest).unwrap()
+        .into_iter()
+        .map(|example| {
+            let input = read_to_string(dir.join(&example.file)).unwrap();
+            (example.part, input, example.expected)
+        })
+        .collect()
+}
+
+/// Returns the saved example input and expected answer for one part, for solutions' tests.
+pub fn example(year: &str, day: &str, part: Part) -> (String, String) {
+    load(year, day).into_iter()
+        .find(|(example_part, _, _)| *example_part == part)
+        .map(|(_, input, expected)| (input, expected))
+        .unwrap_or_else(|| panic!("no saved example for {year} {day} part {part}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = include_str!("../fixtures/puzzle.html");
+    const PAGE_PART_ONE: &str = include_str!("../fixtures/puzzle_part_one.html");
+    const PAGE_BLOCKS: &str = include_str!("../fixtures/puzzle_blocks.html");
+
+    #[test]
+    fn extract_examples() {
+        let expected = vec![
+            (Part::One, String::from("1 + 2\n3 + 4\n"), String::from("10")),
+            (Part::Two, String::from("2 * 3\n"), String::from("6")),
+        ];
+
+        assert_eq!(extract(PAGE), expected);
+    }
+
+    #[test]
+    fn extract_part_one_examples() {
+        let expected = vec![
+            (Part::One, String::from("1 + 2\n3 + 4\n"), String::from("10")),
+        ];
+
+        assert_eq!(extract(PAGE_PART_ONE), expected);
+    }
+
+    #[test]
+    fn pick_input_over_diagrams() {
+        let expected = vec![
+            (Part::One, String::from(".....\n.S-7.\n.|.|.\n.L-J.\n.....\n"), String::from("4")),
+            (Part::Two, String::from("...........\n.S-------7.\n.L-------J.\n"), String::from("0")),
+        ];
+
+        assert_eq!(extract(PAGE_BLOCKS), expected);
+    }
+}
@@ -0,0 +1,243 @@
+use std::{fmt, ops::{Index, IndexMut}};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Pos {
+        Pos { row, col }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+}
+
+/// A rectangular grid of cells stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        if rows.iter().any(|row| row.len() != width) {
+            panic!("grid rows must all be {width} wide");
+        }
+
+        Grid {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        }
+    }
+
+    /// Parses a grid with a cell for each character, one row per line.
+    pub fn parse(input: &str) -> Grid<T> where T: From<char> {
+        Grid::from_rows(input.lines()
+            .map(|line| line.chars().map(T::from).collect())
+            .collect())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        match self.contains(pos) {
+            true => Some(&mut self.cells[pos.row * self.width + pos.col]),
+            false => None,
+        }
+    }
+
+    /// Returns the position one step away in a direction, if it's on the grid.
+    pub fn step(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        let next = match direction {
+            Direction::North => Pos::new(pos.row.checked_sub(1)?, pos.col),
+            Direction::East => Pos::new(pos.row, pos.col + 1),
+            Direction::South => Pos::new(pos.row + 1, pos.col),
+            Direction::West => Pos::new(pos.row, pos.col.checked_sub(1)?),
+        };
+
+        self.contains(next).then_some(next)
+    }
+
+    /// Returns the up to 4 positions that share an edge with `pos`.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL.into_iter().filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// Returns the up to 8 positions that share an edge or a corner with `pos`.
+    pub fn neighbours_with_diagonals(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        (pos.row.saturating_sub(1)..=pos.row + 1)
+            .flat_map(move |row| (pos.col.saturating_sub(1)..=pos.col + 1).map(move |col| Pos::new(row, col)))
+            .filter(move |&neighbour| neighbour != pos && self.contains(neighbour))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| Pos::new(index / width, index % width))
+    }
+
+    /// Iterates over every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width.max(1)).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Flips the grid over its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T> where T: Clone {
+        Grid::from_rows(self.columns().map(|column| column.cloned().collect()).collect())
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T> where T: Clone {
+        Grid::from_rows(self.columns().map(|column| {
+            let mut row = column.cloned().collect::<Vec<T>>();
+            row.reverse();
+            row
+        }).collect())
+    }
+
+    pub fn rotate_anticlockwise(&self) -> Grid<T> where T: Clone {
+        Grid::from_rows((0..self.width).rev().map(|col| self.column(col).cloned().collect()).collect())
+    }
+}
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos).unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+        abc\n\
+        def\n\
+    ";
+
+    #[test]
+    fn parse_and_display() {
+        let grid = Grid::<char>::parse(INPUT);
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(1, 0)], 'd');
+        assert_eq!(grid.get(Pos::new(2, 0)), None);
+        assert_eq!(grid.get(Pos::new(0, 3)), None);
+        assert_eq!(grid.to_string(), INPUT);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::<char>::parse(INPUT);
+
+        assert_eq!(
+            grid.neighbours(Pos::new(0, 0)).collect::<Vec<_>>(),
+            vec![Pos::new(0, 1), Pos::new(1, 0)],
+        );
+        assert_eq!(grid.neighbours(Pos::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbours_with_diagonals(Pos::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours_with_diagonals(Pos::new(1, 1)).count(), 5);
+        assert_eq!(grid.step(Pos::new(0, 2), Direction::East), None);
+        assert_eq!(grid.step(Pos::new(0, 2), Direction::South), Some(Pos::new(1, 2)));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::<char>::parse(INPUT);
+
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&['a', 'b', 'c'][..], &['d', 'e', 'f'][..]]);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.columns().map(|column| column.collect::<String>()).collect::<Vec<_>>(), ["ad", "be", "cf"]);
+        assert_eq!(grid.iter().find(|(_, &cell)| cell == 'e').map(|(pos, _)| pos), Some(Pos::new(1, 1)));
+    }
+
+    #[test]
+    fn transform() {
+        let grid = Grid::<char>::parse(INPUT);
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+        assert_eq!(grid.map(|cell| cell.to_ascii_uppercase()).to_string(), "ABC\nDEF\n");
+    }
+}
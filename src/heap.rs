@@ -0,0 +1,116 @@
+#[cfg(feature = "alloc-stats")]
+use std::{alloc::{GlobalAlloc, Layout, System}, sync::atomic::{AtomicUsize, Ordering::Relaxed}};
+
+#[cfg(feature = "alloc-stats")]
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "alloc-stats")]
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the system allocator, counting the bytes in use and the most that have been.
+#[cfg(feature = "alloc-stats")]
+struct Counting;
+#[cfg(feature = "alloc-stats")]
+impl Counting {
+    fn grow(size: usize) {
+        let current = CURRENT.fetch_add(size, Relaxed) + size;
+        PEAK.fetch_max(current, Relaxed);
+    }
+
+    fn shrink(size: usize) {
+        CURRENT.fetch_sub(size, Relaxed);
+    }
+}
+#[cfg(feature = "alloc-stats")]
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Counting::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Counting::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Counting::shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            match new_size > layout.size() {
+                true => Counting::grow(new_size - layout.size()),
+                false => Counting::shrink(layout.size() - new_size),
+            }
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Runs `f`, returning its result and the most heap it used on top of what was already
+/// allocated, if built with the `alloc-stats` feature.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<usize>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let baseline = CURRENT.load(Relaxed);
+        PEAK.store(baseline, Relaxed);
+
+        let result = f();
+
+        (result, Some(PEAK.load(Relaxed).saturating_sub(baseline)))
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    (f(), None)
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        0..=1023 => format!("{bytes} B"),
+        1024..=1048575 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1048576.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_sizes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1048576), "3.0 MiB");
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn measure_peak() {
+        let (length, peak) = measure(|| vec![0_u8; 1 << 20].len());
+
+        assert_eq!(length, 1 << 20);
+        assert!(peak.unwrap() >= 1 << 20);
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn measure_phases_separately() {
+        let (parsed, parse_peak) = measure(|| vec![0_u8; 1 << 20]);
+        let (_, solve_peak) = measure(|| vec![0_u8; 1024].len() + parsed.len());
+
+        assert!(parse_peak.unwrap() >= 1 << 20);
+        assert!(solve_peak.unwrap() < 1 << 20);
+    }
+}
@@ -1,51 +1,557 @@
-use reqwest::{blocking::Client, header::{COOKIE, HeaderMap, HeaderValue, USER_AGENT}};
-use std::{env, fs::{read_to_string, File}, io::Write};
+use itertools::Itertools;
+use reqwest::{
+    blocking::{Client, RequestBuilder},
+    header::{COOKIE, HeaderMap, HeaderValue, RETRY_AFTER, USER_AGENT},
+};
+use std::{
+    fmt,
+    fs::{create_dir_all, read_to_string, File, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    sync::Mutex,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
-const URL: &str = "https://adventofcode.com";
-const YEAR: &str = "2023";
+use crate::{config::{self, Config}, markdown::{articles, page_to_markdown}};
 
-pub fn get_input(day: &str, test: bool) -> String {
-    match test {
-        true => read_file("test").expect("no test input found!"),
-        false => read_file(day).unwrap_or_else(|| fetch_and_save(day)),
+pub const URL: &str = "https://adventofcode.com";
+
+/// The longest we'll sleep for before a request rather than giving up.
+const MAX_WAIT: Duration = Duration::from_secs(60);
+
+/// How long to back off for when a `Retry-After` header can't be understood.
+const UNKNOWN_RETRY_AFTER: Duration = Duration::from_secs(300);
+
+#[derive(Debug)]
+pub enum InputError {
+    MissingCookie,
+    InvalidCookie,
+    InvalidUserAgent,
+    Request(reqwest::Error),
+    Status(u16),
+    Locked,
+    LoggedOut,
+    MissingTestInput(String),
+    Offline(String),
+    NotUnlocked(Duration),
+    RetryAfter(Duration),
+    NoPuzzle,
+    Io(io::Error),
+}
+impl InputError {
+    /// Picks out the site's known error pages, falling back to the status code.
+    fn from_response(status: u16, body: &str) -> InputError {
+        if body.contains("Please don't repeatedly request this endpoint") {
+            InputError::Locked
+        } else if is_login_page(body) {
+            InputError::LoggedOut
+        } else {
+            InputError::Status(status)
+        }
+    }
+}
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::MissingCookie => write!(f, "no SESSION_COOKIE set"),
+            InputError::InvalidCookie => write!(f, "SESSION_COOKIE isn't a valid header value"),
+            InputError::InvalidUserAgent => write!(f, "user agent isn't a valid header value"),
+            InputError::Request(error) => write!(f, "request failed: {error}"),
+            InputError::Status(status) => write!(f, "request failed with status {status}"),
+            InputError::Locked => write!(f, "puzzle hasn't unlocked yet"),
+            InputError::LoggedOut => write!(f, "not logged in, check SESSION_COOKIE"),
+            InputError::MissingTestInput(path) => write!(f, "no test input found at {path}"),
+            InputError::Offline(path) => write!(f, "{path} isn't cached and we're offline"),
+            InputError::NotUnlocked(wait) => write!(f, "puzzle unlocks in {}", format_wait(*wait)),
+            InputError::RetryAfter(wait) => write!(f, "server asked us to wait, try again in {}", format_wait(*wait)),
+            InputError::NoPuzzle => write!(f, "page has no puzzle description"),
+            InputError::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+impl std::error::Error for InputError {}
+impl From<reqwest::Error> for InputError {
+    fn from(error: reqwest::Error) -> InputError {
+        InputError::Request(error)
+    }
+}
+impl From<io::Error> for InputError {
+    fn from(error: io::Error) -> InputError {
+        InputError::Io(error)
+    }
+}
+
+fn is_login_page(body: &str) -> bool {
+    body.contains("Please log in") || body.contains("[Log In]")
+}
+
+pub trait Http {
+    fn get(&self, url: &str) -> Result<String, InputError>;
+    fn post(&self, url: &str, form: &[(&str, &str)]) -> Result<String, InputError>;
+}
+impl Http for Client {
+    fn get(&self, url: &str) -> Result<String, InputError> {
+        let response = Client::get(self, url).send()?;
+        let status = response.status();
+        let body = response.text()?;
+
+        match status.is_success() {
+            true => Ok(body),
+            false => Err(InputError::from_response(status.as_u16(), &body)),
+        }
+    }
+
+    fn post(&self, url: &str, form: &[(&str, &str)]) -> Result<String, InputError> {
+        let response = Client::post(self, url).form(form).send()?;
+        let status = response.status();
+        let body = response.text()?;
+
+        match status.is_success() {
+            true => Ok(body),
+            false => Err(InputError::from_response(status.as_u16(), &body)),
+        }
+    }
+}
+
+/// A client that spaces out requests, honours `Retry-After` and logs every request.
+///
+/// The log is read once when the client is made, so earlier runs are throttled against too.
+/// After that only the requests that still hold up the next one are kept in memory, while
+/// every request goes on being appended to the log.
+pub struct Throttled {
+    client: Client,
+    log: PathBuf,
+    entries: Mutex<Vec<LogEntry>>,
+    interval: Duration,
+}
+impl Throttled {
+    fn new(client: Client, log: PathBuf, interval: Duration) -> Throttled {
+        let entries = read_to_string(&log).unwrap_or_default()
+            .lines()
+            .last()
+            .and_then(LogEntry::parse)
+            .into_iter()
+            .collect();
+
+        Throttled { client, log, entries: Mutex::new(entries), interval }
+    }
+
+    /// Sleeps until the last logged request allows another, or fails if that's too far off.
+    fn wait(&self) -> Result<(), InputError> {
+        let next_allowed = self.entries.lock().unwrap()
+            .last()
+            .map(|last| last.next_allowed(self.interval));
+
+        let Some(next_allowed) = next_allowed else {
+            return Ok(());
+        };
+
+        let wait = next_allowed.saturating_sub(now());
+
+        if wait > MAX_WAIT {
+            Err(InputError::RetryAfter(wait))
+        } else {
+            thread::sleep(wait);
+            Ok(())
+        }
+    }
+
+    fn send(&self, method: &str, url: &str, request: RequestBuilder) -> Result<String, InputError> {
+        self.wait()?;
+
+        let response = request.send();
+        let mut entry = LogEntry {
+            time: now(),
+            method: method.to_string(),
+            url: url.to_string(),
+            status: None,
+            retry_after: None,
+        };
+
+        let response = match response {
+            Ok(response) => response,
+            Err(error) => {
+                self.record(entry)?;
+                return Err(error.into());
+            },
+        };
+
+        let status = response.status();
+        entry.status = Some(status.as_u16());
+        entry.retry_after = response.headers().get(RETRY_AFTER)
+            .map(|value| value.to_str().ok().and_then(|value| parse_retry_after(value, entry.time)))
+            .map(|retry_after| retry_after.unwrap_or(UNKNOWN_RETRY_AFTER));
+        self.record(entry)?;
+
+        let body = response.text()?;
+
+        match status.is_success() {
+            true => Ok(body),
+            false => Err(InputError::from_response(status.as_u16(), &body)),
+        }
+    }
+
+    fn record(&self, entry: LogEntry) -> Result<(), InputError> {
+        if let Some(dir) = self.log.parent() {
+            create_dir_all(dir)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.log)?;
+        writeln!(file, "{entry}")?;
+
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|old| old.next_allowed(self.interval) > entry.time);
+        entries.push(entry);
+
+        Ok(())
+    }
+}
+impl Http for Throttled {
+    fn get(&self, url: &str) -> Result<String, InputError> {
+        self.send("GET", url, self.client.get(url))
+    }
+
+    fn post(&self, url: &str, form: &[(&str, &str)]) -> Result<String, InputError> {
+        self.send("POST", url, self.client.post(url).form(form))
+    }
+}
+
+/// A line of the request log: `<unix millis> <method> <url> <status> [retry-after=<secs>]`,
+/// with a status of `-` for requests that got no response.
+#[derive(Debug, PartialEq)]
+struct LogEntry {
+    time: Duration,
+    method: String,
+    url: String,
+    status: Option<u16>,
+    retry_after: Option<Duration>,
+}
+impl LogEntry {
+    fn parse(line: &str) -> Option<LogEntry> {
+        let mut fields = line.split_whitespace();
+
+        let time = Duration::from_millis(fields.next()?.parse().ok()?);
+        let method = fields.next()?.to_string();
+        let url = fields.next()?.to_string();
+        let status = fields.next()?.parse().ok();
+        let retry_after = fields.next()
+            .and_then(|field| field.strip_prefix("retry-after="))
+            .and_then(|secs| secs.parse().ok())
+            .map(Duration::from_secs);
+
+        Some(LogEntry { time, method, url, status, retry_after })
+    }
+
+    /// Returns when the next request may be made, as time since the epoch.
+    fn next_allowed(&self, interval: Duration) -> Duration {
+        self.time + self.retry_after.unwrap_or_default().max(interval)
+    }
+}
+impl fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} ", self.time.as_millis(), self.method, self.url)?;
+
+        match self.status {
+            Some(status) => write!(f, "{status}")?,
+            None => write!(f, "-")?,
+        }
+
+        match self.retry_after {
+            Some(retry_after) => write!(f, " retry-after={}", retry_after.as_secs()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Reads a `Retry-After` value, either a number of seconds or an HTTP date such as
+/// `Wed, 21 Oct 2015 07:28:00 GMT`, as how long to wait from `now`.
+fn parse_retry_after(value: &str, now: Duration) -> Option<Duration> {
+    let value = value.trim();
+
+    if let Ok(secs) = value.parse() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let (_weekday, date) = value.split_once(", ")?;
+    let [day, month, year, time, "GMT"] = date.split_whitespace().collect::<Vec<&str>>()[..] else {
+        return None;
+    };
+    let month = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"]
+        .iter()
+        .position(|&name| name == month)? as i64 + 1;
+    let (hour, minute, second) = time.split(':').map(|n| n.parse::<i64>().ok()).collect_tuple()?;
+
+    let secs = days_since_epoch(year.parse().ok()?, month, day.parse().ok()?) * 86400
+        + hour? * 3600 + minute? * 60 + second?;
+
+    Some(Duration::from_secs(u64::try_from(secs).ok()?).saturating_sub(now))
+}
+
+fn now() -> Duration {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap()
+}
+
+fn format_wait(wait: Duration) -> String {
+    let secs = wait.as_secs();
+
+    match (secs / 86400, secs / 3600 % 24, secs / 60 % 60, secs % 60) {
+        (0, 0, 0, s) => format!("{s}s"),
+        (0, 0, m, s) => format!("{m}m {s}s"),
+        (0, h, m, s) => format!("{h}h {m}m {s}s"),
+        (d, h, m, s) => format!("{d}d {h}h {m}m {s}s"),
+    }
+}
+
+/// Returns how long until a puzzle unlocks at midnight UTC-5 on its day in December, if it
+/// hasn't yet.
+fn time_until_unlock(year: &str, day: &str, now: Duration) -> Option<Duration> {
+    let year = year.parse::<i64>().ok()?;
+    let day = day.parse::<i64>().ok()?;
+
+    let unlock = Duration::from_secs((days_since_epoch(year, 12, day) * 86400 + 5 * 3600) as u64);
+
+    unlock.checked_sub(now).filter(|wait| !wait.is_zero())
+}
+
+/// Counts the days from 1970-01-01 to a date in the proleptic Gregorian calendar.
+fn days_since_epoch(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+fn check_unlocked(year: &str, day: &str) -> Result<(), InputError> {
+    match time_until_unlock(year, day, now()) {
+        Some(wait) => Err(InputError::NotUnlocked(wait)),
+        None => Ok(()),
+    }
+}
+
+pub fn get_input(year: &str, day: &str) -> Result<String, InputError> {
+    load_input(config::get(), year, day)
+}
+
+/// Reads a cached input, fetching and saving it if it's missing and we aren't offline.
+pub fn load_input(config: &Config, year: &str, day: &str) -> Result<String, InputError> {
+    let path = input_path(config, year, day);
+
+    match read_to_string(&path) {
+        Ok(input) => Ok(input),
+        Err(_) if config.offline => Err(InputError::Offline(path.display().to_string())),
+        Err(_) => fetch_and_save(config, year, day),
     }
 }
 
-fn read_file(file_name: &str) -> Option<String> {
-    let path = format!("./input/{file_name}.txt");
-    read_to_string(path).ok()
+/// Reads `day_NN.test.txt`, or `day_NN.test.<variant>.txt` for a named variant.
+pub fn get_test_input(year: &str, day: &str, variant: Option<&str>) -> Result<String, InputError> {
+    let file_name = match variant {
+        Some(variant) => format!("{day}.test.{variant}"),
+        None => format!("{day}.test"),
+    };
+    let path = input_path(config::get(), year, &file_name);
+
+    read_to_string(&path).map_err(|_| InputError::MissingTestInput(path.display().to_string()))
 }
 
-fn fetch_and_save(file_name: &str) -> String {
-    let client = get_client();
+fn input_path(config: &Config, year: &str, file_name: &str) -> PathBuf {
+    config.input_dir.join(year).join(format!("{file_name}.txt"))
+}
 
-    let day = file_name.replace("day_0", "").replace("day_", "");
-    let url = format!("{URL}/{YEAR}/day/{day}/input");
+fn day_number(file_name: &str) -> String {
+    file_name.replace("day_0", "").replace("day_", "")
+}
 
-    let input = client.get(url).send().unwrap().text().unwrap();
+/// Checks that a successful response is really an input rather than an error or login page.
+fn validate_input(body: &str) -> Result<(), InputError> {
+    if body.contains("Please don't repeatedly request this endpoint") {
+        Err(InputError::Locked)
+    } else if is_login_page(body) || body.trim_start().starts_with("<!DOCTYPE") {
+        Err(InputError::LoggedOut)
+    } else {
+        Ok(())
+    }
+}
 
-    let path = format!("./input/{file_name}.txt");
-    let mut file = File::create(path).unwrap();
-    file.write_all(input.as_bytes()).ok();
+/// Checks that a puzzle page is worth caching, which error and login pages aren't.
+fn validate_page(page: &str) -> Result<(), InputError> {
+    if page.contains("Please don't repeatedly request this endpoint") {
+        Err(InputError::Locked)
+    } else if is_login_page(page) {
+        Err(InputError::LoggedOut)
+    } else if articles(page).is_empty() {
+        Err(InputError::NoPuzzle)
+    } else {
+        Ok(())
+    }
+}
+
+fn fetch_and_save(config: &Config, year: &str, file_name: &str) -> Result<String, InputError> {
+    let day = day_number(file_name);
+    check_unlocked(year, &day)?;
+
+    let client = get_client(config)?;
+    let url = format!("{}/{year}/day/{day}/input", config.base_url);
+
+    let input = Http::get(&client, &url)?;
+    validate_input(&input)?;
 
-    input
+    create_dir_all(config.input_dir.join(year))?;
+    let mut file = File::create(input_path(config, year, file_name))?;
+    file.write_all(input.as_bytes())?;
+
+    Ok(input)
+}
+
+/// Returns the puzzle page, fetching it again if part two hasn't been saved yet.
+pub fn get_puzzle_page(year: &str, day: &str) -> Result<String, InputError> {
+    load_puzzle_page(config::get(), year, day)
 }
 
-fn get_client() -> Client {
-    let session_cookie = env::var("SESSION_COOKIE").expect("no SESSION_COOKIE set!");
+/// Reads a cached puzzle page, fetching it if it's missing or has no part two yet.
+pub fn load_puzzle_page(config: &Config, year: &str, day: &str) -> Result<String, InputError> {
+    let dir = config.cache_dir.join(year);
+    let path = dir.join(format!("{day}.html"));
+
+    // pages cached before they were validated may not be puzzles at all
+    let cached = read_to_string(&path).ok().filter(|page| validate_page(page).is_ok());
+
+    match cached {
+        Some(page) if articles(&page).len() > 1 => Ok(page),
+        Some(page) if config.offline => Ok(page),
+        None if config.offline => Err(InputError::Offline(path.display().to_string())),
+        _ => {
+            check_unlocked(year, &day_number(day))?;
+
+            let client = get_client(config)?;
+            let url = format!("{}/{year}/day/{}", config.base_url, day_number(day));
+
+            let page = Http::get(&client, &url)?;
+            validate_page(&page)?;
+
+            create_dir_all(dir)?;
+            let mut file = File::create(path)?;
+            file.write_all(page.as_bytes())?;
+
+            Ok(page)
+        },
+    }
+}
+
+/// Returns the puzzle description as Markdown, saving it to `puzzles/`.
+pub fn get_puzzle(year: &str, day: &str) -> Result<String, InputError> {
+    let puzzle = page_to_markdown(&get_puzzle_page(year, day)?);
+
+    let dir = config::get().path("puzzles").join(year);
+    create_dir_all(&dir)?;
+    let mut file = File::create(dir.join(format!("{day}.md")))?;
+    file.write_all(puzzle.as_bytes())?;
+
+    Ok(puzzle)
+}
+
+pub fn get_client(config: &Config) -> Result<Throttled, InputError> {
+    let session_cookie = config.session_cookie.as_ref().ok_or(InputError::MissingCookie)?;
     let session_cookie = format!("session={session_cookie}");
 
     let mut headers = HeaderMap::new();
     headers.insert(
         COOKIE,
-        HeaderValue::from_str(&session_cookie).unwrap(),
+        HeaderValue::from_str(&session_cookie).map_err(|_| InputError::InvalidCookie)?,
     );
     headers.insert(
         USER_AGENT,
-        HeaderValue::from_static("https://github.com/Ben-Wormald/advent-of-code-2023"),
+        HeaderValue::from_str(&config.user_agent).map_err(|_| InputError::InvalidUserAgent)?,
     );
 
-    Client::builder()
-        .default_headers(headers)
-        .build().unwrap()
+    Ok(Throttled::new(
+        Client::builder()
+            .default_headers(headers)
+            .build()?,
+        config.cache_dir.join("requests.log"),
+        config.request_interval,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn classify_error_responses() {
+        let locked = "Please don't repeatedly request this endpoint before it unlocks! \
+            The calendar countdown is synchronized with the server time; \
+            the link will be enabled on the calendar the instant this puzzle becomes available.\n";
+        let logged_out = "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+
+        assert!(matches!(InputError::from_response(404, locked), InputError::Locked));
+        assert!(matches!(InputError::from_response(400, logged_out), InputError::LoggedOut));
+        assert!(matches!(InputError::from_response(500, "oops"), InputError::Status(500)));
+    }
+
+    #[test]
+    fn validate_inputs() {
+        let login_page = "<!DOCTYPE html>\n<html lang=\"en-us\">\n<a href=\"/2023/auth/login\">[Log In]</a>";
+
+        assert!(validate_input("1abc2\npqr3stu8vwx\n").is_ok());
+        assert!(matches!(validate_input(login_page), Err(InputError::LoggedOut)));
+        assert!(matches!(
+            validate_input("Please don't repeatedly request this endpoint before it unlocks!"),
+            Err(InputError::Locked),
+        ));
+    }
+
+    #[test]
+    fn validate_pages() {
+        let logged_out = "<article class=\"day-desc\"><h2>--- Day 1 ---</h2></article>\
+            <a href=\"/2023/auth/login\">[Log In]</a>";
+
+        assert!(validate_page(include_str!("../fixtures/puzzle.html")).is_ok());
+        assert!(matches!(validate_page(logged_out), Err(InputError::LoggedOut)));
+        assert!(matches!(validate_page("<main>nothing here</main>"), Err(InputError::NoPuzzle)));
+    }
+
+    #[test]
+    fn log_entries() {
+        let line = "1701406800000 GET https://adventofcode.com/2023/day/1/input 429 retry-after=30";
+        let entry = LogEntry::parse(line).unwrap();
+
+        assert_eq!(entry.status, Some(429));
+        assert_eq!(entry.retry_after, Some(Duration::from_secs(30)));
+        assert_eq!(entry.to_string(), line);
+        assert_eq!(entry.next_allowed(Duration::from_secs(5)), Duration::from_millis(1701406830000));
+
+        let entry = LogEntry::parse("1701406800000 POST https://adventofcode.com/2023/day/1/answer -").unwrap();
+
+        assert_eq!(entry.status, None);
+        assert_eq!(entry.next_allowed(Duration::from_secs(5)), Duration::from_millis(1701406805000));
+    }
+
+    #[test]
+    fn retry_after_values() {
+        let now = Duration::from_secs(1445412470);
+
+        assert_eq!(parse_retry_after("120", now), Some(Duration::from_secs(120)));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT", now), Some(Duration::from_secs(10)));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:27:00 GMT", now), Some(Duration::ZERO));
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[test]
+    fn unlock_countdown() {
+        // 2023-12-01T05:00:00Z, midnight in UTC-5
+        let unlock = Duration::from_secs(1701406800);
+
+        assert_eq!(time_until_unlock("2023", "1", unlock - Duration::from_secs(90)), Some(Duration::from_secs(90)));
+        assert_eq!(time_until_unlock("2023", "1", unlock), None);
+        assert_eq!(time_until_unlock("2023", "2", unlock), Some(Duration::from_secs(86400)));
+        assert_eq!(format_wait(Duration::from_secs(90061)), "1d 1h 1m 1s");
+        assert_eq!(format_wait(Duration::from_secs(65)), "1m 5s");
+    }
 }
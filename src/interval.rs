@@ -0,0 +1,270 @@
+use std::ops::{Add, Range, Sub};
+
+/// A set of values stored as sorted, non-overlapping half-open ranges.
+///
+/// Set operations always return coalesced sets, where no two ranges touch. Splitting can
+/// leave touching ranges behind, which `coalesce` merges back together.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges.get(index).is_some_and(|range| range.contains(&value))
+    }
+
+    /// Adds a range, merging it with any ranges it overlaps or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|existing| existing.end < range.start);
+        let last = self.ranges.partition_point(|existing| existing.start <= range.end);
+
+        let merged = match &self.ranges[first..last] {
+            [] => range,
+            touching => touching[0].start.min(range.start)..touching[touching.len() - 1].end.max(range.end),
+        };
+
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.coalesce();
+        for range in other.ranges.iter() {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        IntervalSet { ranges }.coalesce()
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let mut others = other.ranges.iter().peekable();
+
+        for range in self.ranges.iter() {
+            let mut start = range.start;
+
+            while let Some(other) = others.peek() {
+                if other.end <= start {
+                    others.next();
+                    continue;
+                }
+                if other.start >= range.end {
+                    break;
+                }
+
+                if start < other.start {
+                    ranges.push(start..other.start);
+                }
+                start = start.max(other.end);
+
+                if other.end > range.end {
+                    break;
+                }
+                others.next();
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        IntervalSet { ranges }.coalesce()
+    }
+
+    /// Splits any range that a point falls strictly inside, so the point starts a new range.
+    pub fn split_at(&self, points: &[T]) -> IntervalSet<T> {
+        let mut points = points.to_vec();
+        points.sort();
+        points.dedup();
+
+        let ranges = self.ranges.iter().flat_map(|range| {
+            let inside = points.iter().filter(|&&point| range.start < point && point < range.end);
+            let starts = [range.start].into_iter().chain(inside.copied()).collect::<Vec<T>>();
+            let ends = starts.iter().skip(1).copied().chain([range.end]).collect::<Vec<T>>();
+
+            starts.into_iter().zip(ends).map(|(start, end)| start..end).collect::<Vec<Range<T>>>()
+        }).collect();
+
+        IntervalSet { ranges }
+    }
+
+    /// Merges ranges that touch end to start.
+    pub fn coalesce(&self) -> IntervalSet<T> {
+        let mut ranges: Vec<Range<T>> = Vec::with_capacity(self.ranges.len());
+
+        for range in self.ranges.iter() {
+            match ranges.last_mut() {
+                Some(last) if last.end == range.start => last.end = range.end,
+                _ => ranges.push(range.clone()),
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    pub fn shift_up(&self, offset: T) -> IntervalSet<T> where T: Add<Output = T> {
+        IntervalSet {
+            ranges: self.ranges.iter().map(|range| (range.start + offset)..(range.end + offset)).collect(),
+        }
+    }
+
+    pub fn shift_down(&self, offset: T) -> IntervalSet<T> where T: Sub<Output = T> {
+        IntervalSet {
+            ranges: self.ranges.iter().map(|range| (range.start - offset)..(range.end - offset)).collect(),
+        }
+    }
+}
+impl<T: Copy + Ord> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet::new()
+    }
+}
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    fn points(set: &IntervalSet<u8>) -> HashSet<u8> {
+        set.ranges().iter().flat_map(|range| range.clone()).collect()
+    }
+
+    fn is_valid(set: &IntervalSet<u8>) -> bool {
+        set.ranges().iter().all(|range| !range.is_empty())
+            && set.ranges().windows(2).all(|pair| pair[0].end <= pair[1].start)
+    }
+
+    fn is_coalesced(set: &IntervalSet<u8>) -> bool {
+        is_valid(set) && set.ranges().windows(2).all(|pair| pair[0].end < pair[1].start)
+    }
+
+    fn interval_set() -> impl Strategy<Value = IntervalSet<u8>> {
+        prop::collection::vec((0_u8..100, 0_u8..20), 0..8)
+            .prop_map(|ranges| ranges.into_iter().map(|(start, len)| start..start + len).collect())
+    }
+
+    #[test]
+    fn insert_merges() {
+        let mut set = IntervalSet::from(0..5);
+        set.insert(10..15);
+        set.insert(5..7);
+        set.insert(14..20);
+
+        assert_eq!(set.ranges(), [0..7, 10..20]);
+
+        set.insert(3..12);
+
+        assert_eq!(set, IntervalSet::from(0..20));
+        assert!(set.contains(19));
+        assert!(!set.contains(20));
+    }
+
+    #[test]
+    fn split_and_coalesce() {
+        let set = IntervalSet::from_iter([0..10, 20..30]);
+        let split = set.split_at(&[5, 10, 25, 40]);
+
+        assert_eq!(split.ranges(), [0..5, 5..10, 20..25, 25..30]);
+        assert_eq!(split.coalesce(), set);
+    }
+
+    proptest! {
+        #[test]
+        fn matches_model(a in interval_set(), b in interval_set()) {
+            let (model_a, model_b) = (points(&a), points(&b));
+
+            let union = a.union(&b);
+            let intersection = a.intersection(&b);
+            let difference = a.difference(&b);
+
+            prop_assert!(is_coalesced(&a) && is_coalesced(&union));
+            prop_assert!(is_coalesced(&intersection) && is_coalesced(&difference));
+            prop_assert_eq!(points(&union), &model_a | &model_b);
+            prop_assert_eq!(points(&intersection), &model_a & &model_b);
+            prop_assert_eq!(points(&difference), &model_a - &model_b);
+        }
+
+        #[test]
+        fn contains_matches_model(a in interval_set(), value in 0_u8..130) {
+            prop_assert_eq!(a.contains(value), points(&a).contains(&value));
+        }
+
+        #[test]
+        fn split_keeps_points(a in interval_set(), at in prop::collection::vec(0_u8..130, 0..5)) {
+            let split = a.split_at(&at);
+
+            prop_assert!(is_valid(&split));
+            prop_assert_eq!(points(&split), points(&a));
+            prop_assert!(split.ranges().iter().all(|range| at.iter().all(|&point| !(range.start < point && point < range.end))));
+            prop_assert_eq!(split.coalesce(), a);
+        }
+
+        #[test]
+        fn shift_moves_points(a in interval_set(), offset in 0_u8..100) {
+            let up = a.shift_up(offset);
+            let model = points(&a).into_iter().map(|point| point + offset).collect::<HashSet<u8>>();
+
+            prop_assert!(is_coalesced(&up));
+            prop_assert_eq!(points(&up), model);
+            prop_assert_eq!(up.shift_down(offset), a);
+        }
+    }
+}
@@ -0,0 +1,17 @@
+pub mod answers;
+pub mod bench;
+pub mod config;
+pub mod examples;
+pub mod grid;
+pub mod heap;
+pub mod input;
+pub mod interval;
+pub mod markdown;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+pub mod output;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+pub mod watch;
+pub mod solutions;
@@ -1,37 +1,263 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use std::{panic, path::PathBuf, process, time::Instant};
 
-mod input;
-use input::get_input;
-
-macro_rules! day {
-    ($day:ident) => {
-        mod solutions { pub mod $day; }
-        use solutions::$day::*;
-
-        const INPUT: &str = stringify!($day);
-    };
-}
+use advent_of_code_2023::{
+    answers::{verify, Mode},
+    bench::bench,
+    config::{self, Format, Overrides},
+    examples,
+    heap::format_bytes,
+    input::{get_client, get_input, get_puzzle, get_puzzle_page, get_test_input, InputError},
+    output::Record,
+    runner::run_all,
+    scaffold::new_day,
+    submit::submit,
+    watch::watch,
+    solutions::{get_day, get_days, latest_year, Day, Part},
+};
 
 #[derive(Parser)]
+#[command(subcommand_negates_reqs = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// defaults to `part` in `aoc.toml`
     #[arg(short, long)]
-    part: String,
+    part: Option<Part>,
+    /// use `input/day_NN.test.txt`, or `day_NN.test.<VARIANT>.txt` if a variant is given
+    #[arg(short, long, value_name = "VARIANT", conflicts_with_all = ["record", "check", "submit"])]
+    test: Option<Option<String>>,
+    /// defaults to the latest registered day
     #[arg(short, long)]
-    test: bool,
+    day: Option<usize>,
+    /// defaults to the latest registered year
+    #[arg(short, long, global = true)]
+    year: Option<String>,
+    /// where inputs are read from and saved to, defaults to `input/`
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+    /// where puzzle pages are cached, defaults to `.cache/`
+    #[arg(long, global = true)]
+    cache_dir: Option<PathBuf>,
+    #[arg(long, global = true)]
+    user_agent: Option<String>,
+    /// print plain answers, or a JSON object per run
+    #[arg(long, global = true)]
+    format: Option<Format>,
+    /// defaults to https://adventofcode.com
+    #[arg(long, global = true)]
+    base_url: Option<String>,
+    /// only use cached inputs and puzzle pages, never fetch them
+    #[arg(long, global = true)]
+    offline: bool,
+    #[command(flatten)]
+    verify: Verify,
+    /// post the answer to the puzzle site
+    #[arg(long)]
+    submit: bool,
+    /// report how long loading, parsing and solving took, and peak heap use with the
+    /// `alloc-stats` feature
+    #[arg(long)]
+    time: bool,
+}
+
+#[derive(clap::Args)]
+struct Verify {
+    /// save the answers to `answers/`
+    #[arg(long, conflicts_with = "check")]
+    record: bool,
+    /// compare the answers against those saved in `answers/`
+    #[arg(long)]
+    check: bool,
+}
+impl Verify {
+    fn mode(&self) -> Option<Mode> {
+        match (self.record, self.check) {
+            (true, _) => Some(Mode::Record),
+            (_, true) => Some(Mode::Check),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// run both parts of every registered day
+    RunAll {
+        #[command(flatten)]
+        verify: Verify,
+    },
+    /// time parsing and solving a day's part over many runs
+    Bench {
+        #[arg(short, long)]
+        part: Part,
+        /// defaults to the latest registered day
+        #[arg(short, long)]
+        day: Option<usize>,
+        #[arg(short, long, default_value_t = 100)]
+        runs: usize,
+        /// percentage slowdown against the saved baseline to warn about
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        /// save this run as the baseline to compare later runs against
+        #[arg(long)]
+        save_baseline: bool,
+    },
+    /// download a day's puzzle description to `puzzles/` as Markdown
+    Puzzle {
+        #[arg(short, long)]
+        day: usize,
+    },
+    /// extract a day's examples and expected answers from its puzzle page into `examples/`
+    Examples {
+        #[arg(short, long)]
+        day: usize,
+    },
+    /// create and register a new day from `day_00.rs`, fetching its input and examples
+    New {
+        day: usize,
+    },
+    /// rebuild, test and run both parts of a day whenever its solution or inputs change
+    Watch {
+        /// defaults to the latest registered day
+        #[arg(short, long)]
+        day: Option<usize>,
+    },
+}
+
+fn find_day(year: &str, number: Option<usize>) -> &'static Day {
+    match number {
+        Some(number) => get_day(year, number).unwrap_or_else(|| panic!("no solution for {year} day {number}!")),
+        None => find_days(year).last().unwrap(),
+    }
+}
+
+fn find_days(year: &str) -> &'static [Day] {
+    get_days(year).unwrap_or_else(|| panic!("no solutions for {year}!"))
 }
 
-day!(day_12);
+fn exit_on_error<T>(result: Result<T, InputError>) -> T {
+    result.unwrap_or_else(|error| {
+        eprintln!("error: {error}");
+        process::exit(1);
+    })
+}
 
 fn main() {
     dotenv::dotenv().ok();
     let args = Args::parse();
 
-    let input = get_input(INPUT, args.test);
+    let config = config::init(Overrides {
+        input_dir: args.input_dir,
+        cache_dir: args.cache_dir,
+        year: args.year,
+        user_agent: args.user_agent,
+        part: args.part,
+        format: args.format,
+        base_url: args.base_url,
+        offline: args.offline,
+    }).unwrap_or_else(|error| {
+        eprintln!("error: {error}");
+        process::exit(1);
+    });
+    let year = config.year.as_deref().unwrap_or_else(|| latest_year());
+
+    match args.command {
+        Some(Command::RunAll { verify }) => {
+            // keep panicking solutions from interleaving their messages with the table
+            panic::set_hook(Box::new(|_| {}));
 
-    let solution = match args.part.as_str() {
-        "1" | "one" => solve_part_one(&input),
-        "2" | "two" => solve_part_two(&input),
-        _ => panic!(),
+            if !run_all(find_days(year), verify.mode(), config.format) {
+                process::exit(1);
+            }
+            return;
+        },
+        Some(Command::Bench { part, day, runs, threshold, save_baseline }) => {
+            return exit_on_error(bench(find_day(year, day), part, runs, threshold, save_baseline));
+        },
+        Some(Command::Puzzle { day }) => {
+            return println!("{}", exit_on_error(get_puzzle(year, &format!("day_{day:02}"))));
+        },
+        Some(Command::Examples { day }) => {
+            let day = format!("day_{day:02}");
+            let page = exit_on_error(get_puzzle_page(year, &day));
+            for example in examples::save(year, &day, &page) {
+                println!("part {}: {} expects {}", example.part, example.file, example.expected);
+            }
+            return;
+        },
+        Some(Command::New { day }) => {
+            return new_day(year, day);
+        },
+        Some(Command::Watch { day }) => {
+            return watch(find_day(year, day));
+        },
+        None => {},
+    }
+
+    let day = find_day(year, args.day);
+
+    let Some(part) = config.part else {
+        eprintln!("error: no part given, pass --part or set one in aoc.toml");
+        process::exit(1);
     };
-    println!("{solution}");
+
+    let start = Instant::now();
+    let input = exit_on_error(match &args.test {
+        Some(variant) => get_test_input(year, day.name, variant.as_deref()),
+        None => get_input(year, day.name),
+    });
+    let load = start.elapsed();
+
+    let timed = (day.run)(&input, part);
+    let solution = &timed.answer;
+    match config.format {
+        Format::Text => {
+            println!("{solution}");
+
+            if args.time {
+                println!("load  {:>12.2?}", load);
+                match (timed.parse_heap, timed.solve_heap) {
+                    (Some(parse_heap), Some(solve_heap)) => {
+                        println!("parse {:>12.2?}  {:>10} heap", timed.parse, format_bytes(parse_heap));
+                        println!("solve {:>12.2?}  {:>10} heap", timed.solve, format_bytes(solve_heap));
+                    },
+                    _ => {
+                        println!("parse {:>12.2?}", timed.parse);
+                        println!("solve {:>12.2?}", timed.solve);
+                        println!("heap  {:>12}", "n/a, build with --features alloc-stats");
+                    },
+                }
+            }
+        },
+        Format::Json => Record::new(year, day.number(), part)
+            .input(&input)
+            .solved(&timed)
+            .loaded(load)
+            .print(),
+    }
+
+    if let Some(mode) = args.verify.mode() {
+        if !verify(mode, year, day.name, part, solution) {
+            process::exit(1);
+        }
+    }
+
+    if args.submit {
+        if config.offline {
+            eprintln!("error: can't submit while offline");
+            process::exit(1);
+        }
+
+        let message = match submit(&exit_on_error(get_client(config)), &config.base_url, year, day.name, part, solution) {
+            Ok(verdict) => verdict.to_string(),
+            Err(reason) => format!("not submitted: {reason}"),
+        };
+
+        // keep stdout to one JSON object per line
+        match config.format {
+            Format::Text => println!("{message}"),
+            Format::Json => eprintln!("{message}"),
+        }
+    }
 }
@@ -0,0 +1,190 @@
+/// Returns the inner HTML of every `<article>` on a page.
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else { break };
+        let content_start = start + open_end + 1;
+        let Some(end) = rest[content_start..].find("</article>") else { break };
+
+        articles.push(&rest[content_start..content_start + end]);
+        rest = &rest[content_start + end..];
+    }
+
+    articles
+}
+
+pub fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Splits HTML into tags and text, yielding `(true, tag)` for the contents of `<...>`.
+fn tokens(html: &str) -> impl Iterator<Item = (bool, &str)> {
+    let mut rest = html;
+
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        if let Some(tag) = rest.strip_prefix('<') {
+            let end = tag.find('>').unwrap_or(tag.len());
+            rest = tag.get(end + 1..).unwrap_or("");
+            Some((true, &tag[..end]))
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let text = &rest[..end];
+            rest = &rest[end..];
+            Some((false, text))
+        }
+    })
+}
+
+/// Returns the text of some HTML with its tags removed.
+pub fn strip_tags(html: &str) -> String {
+    tokens(html)
+        .filter(|(is_tag, _)| !is_tag)
+        .map(|(_, text)| decode_entities(text))
+        .collect()
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')?;
+    Some(&tag[start..start + end])
+}
+
+/// Converts the HTML of a puzzle article to Markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut links = Vec::new();
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut em_in_code = false;
+
+    for (is_tag, token) in tokens(html) {
+        if !is_tag {
+            // skip the line breaks between block elements
+            if !in_pre && token.trim().is_empty() && token.contains('\n') {
+                continue;
+            }
+            markdown.push_str(&decode_entities(token));
+            continue;
+        }
+
+        let name = token.split_whitespace().next().unwrap_or("");
+
+        match name {
+            "h2" => markdown.push_str("## "),
+            "/h2" | "/p" => markdown.push_str("\n\n"),
+            "pre" => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            },
+            "/pre" => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            },
+            "code" if !in_pre => {
+                in_code = true;
+                markdown.push('`');
+            },
+            "/code" if !in_pre => {
+                in_code = false;
+                markdown.push('`');
+                if em_in_code {
+                    em_in_code = false;
+                    markdown.push_str("**");
+                }
+            },
+            // `<code><em>x</em></code>` becomes **`x`**, as emphasis can't go inside code
+            "em" if in_code && markdown.ends_with('`') => {
+                em_in_code = true;
+                markdown.insert_str(markdown.len() - 1, "**");
+            },
+            "em" | "/em" if !in_pre && !in_code => markdown.push_str("**"),
+            "li" => markdown.push_str("- "),
+            "/li" => markdown.push('\n'),
+            "/ul" => markdown.push('\n'),
+            "a" => {
+                links.push(attribute(token, "href").unwrap_or("").to_string());
+                markdown.push('[');
+            },
+            "/a" => {
+                let href = links.pop().unwrap_or_default();
+                markdown.push_str(&format!("]({href})"));
+            },
+            _ => {},
+        }
+    }
+
+    markdown.trim_end().to_string() + "\n"
+}
+
+/// Converts every article on a puzzle page to Markdown, one after another.
+pub fn page_to_markdown(html: &str) -> String {
+    articles(html)
+        .into_iter()
+        .map(to_markdown)
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = include_str!("../fixtures/puzzle.html");
+    const PAGE_PART_ONE: &str = include_str!("../fixtures/puzzle_part_one.html");
+
+    #[test]
+    fn convert_page() {
+        let expected = "\
+            ## --- Day 99: Example Puzzle ---\n\
+            \n\
+            The elves need you to add up some **numbers**.\n\
+            \n\
+            For example:\n\
+            \n\
+            ```\n\
+            1 + 2\n\
+            3 + 4\n\
+            ```\n\
+            \n\
+            - The first line sums to `3`.\n\
+            - The second line sums to `7`.\n\
+            \n\
+            In this example, the total is **`10`**. See [the about page](/2023/about) for more & less.\n\
+            \n\
+            ## --- Part Two ---\n\
+            \n\
+            Now multiply instead:\n\
+            \n\
+            ```\n\
+            2 * 3\n\
+            ```\n\
+            \n\
+            The product is **`6`**.\n\
+        ";
+
+        assert_eq!(page_to_markdown(PAGE), expected);
+    }
+
+    #[test]
+    fn convert_part_one_only() {
+        let markdown = page_to_markdown(PAGE_PART_ONE);
+
+        assert!(markdown.starts_with("## --- Day 99: Example Puzzle ---\n"));
+        assert!(!markdown.contains("Part Two"));
+    }
+}
@@ -0,0 +1,106 @@
+//! A local HTTP server for testing fetching without touching the real puzzle site.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+pub struct Route {
+    pub method: &'static str,
+    pub path: String,
+    pub status: u16,
+    pub headers: Vec<(&'static str, String)>,
+    pub body: String,
+}
+impl Route {
+    pub fn get(path: &str, status: u16, body: &str) -> Route {
+        Route { method: "GET", path: path.to_string(), status, headers: Vec::new(), body: body.to_string() }
+    }
+
+    pub fn post(path: &str, status: u16, body: &str) -> Route {
+        Route { method: "POST", path: path.to_string(), status, headers: Vec::new(), body: body.to_string() }
+    }
+
+    pub fn header(mut self, name: &'static str, value: &str) -> Route {
+        self.headers.push((name, value.to_string()));
+        self
+    }
+}
+
+/// A request the server received.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: String,
+}
+
+/// A stand-in for the puzzle site that serves canned responses on a local port, answering
+/// anything it doesn't know with a 404.
+pub struct MockServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+impl MockServer {
+    pub fn start(routes: Vec<Route>) -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let received = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut reader = BufReader::new(stream.unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut parts = request_line.split_whitespace();
+                let method = parts.next().unwrap_or_default().to_string();
+                let path = parts.next().unwrap_or_default().to_string();
+
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header == "\r\n" || header.is_empty() {
+                        break;
+                    }
+                    if let Some(length) = header.to_lowercase().strip_prefix("content-length: ") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                }
+
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+
+                let route = routes.iter().find(|route| route.method == method && route.path == path);
+                let (status, response) = route
+                    .map(|route| (route.status, route.body.as_str()))
+                    .unwrap_or((404, "Not Found"));
+                let headers = route
+                    .map(|route| route.headers.iter().map(|(name, value)| format!("{name}: {value}\r\n")).collect())
+                    .unwrap_or_else(String::new);
+
+                received.lock().unwrap().push(Request {
+                    method,
+                    path,
+                    body: String::from_utf8(body).unwrap(),
+                });
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} Mock\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                    response.len(),
+                ).unwrap();
+            }
+        });
+
+        MockServer { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
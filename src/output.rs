@@ -0,0 +1,127 @@
+use serde::Serialize;
+use std::time::Duration;
+
+use crate::solutions::{Answer, Part, Timed};
+
+/// A single run of one part, as printed by `--format json`.
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    pub year: &'a str,
+    pub day: usize,
+    pub part: u8,
+    pub answer: Option<&'a Answer>,
+    pub answer_type: Option<&'static str>,
+    pub load_ns: Option<u128>,
+    pub parse_ns: Option<u128>,
+    pub solve_ns: Option<u128>,
+    pub parse_peak_heap_bytes: Option<usize>,
+    pub solve_peak_heap_bytes: Option<usize>,
+    pub input_checksum: Option<String>,
+    pub error: Option<String>,
+}
+impl<'a> Record<'a> {
+    pub fn new(year: &'a str, day: usize, part: Part) -> Record<'a> {
+        Record {
+            year,
+            day,
+            part: match part {
+                Part::One => 1,
+                Part::Two => 2,
+            },
+            answer: None,
+            answer_type: None,
+            load_ns: None,
+            parse_ns: None,
+            solve_ns: None,
+            parse_peak_heap_bytes: None,
+            solve_peak_heap_bytes: None,
+            input_checksum: None,
+            error: None,
+        }
+    }
+
+    pub fn input(mut self, input: &str) -> Record<'a> {
+        self.input_checksum = Some(checksum(input));
+        self
+    }
+
+    /// Adds the answer along with how long each phase took and its peak heap use, where known.
+    pub fn solved(mut self, timed: &'a Timed) -> Record<'a> {
+        self.answer = Some(&timed.answer);
+        self.answer_type = Some(timed.answer.kind());
+        self.parse_ns = Some(timed.parse.as_nanos());
+        self.solve_ns = Some(timed.solve.as_nanos());
+        self.parse_peak_heap_bytes = timed.parse_heap;
+        self.solve_peak_heap_bytes = timed.solve_heap;
+        self
+    }
+
+    /// Adds how long the input took to load.
+    pub fn loaded(mut self, load: Duration) -> Record<'a> {
+        self.load_ns = Some(load.as_nanos());
+        self
+    }
+
+    pub fn error(mut self, error: impl ToString) -> Record<'a> {
+        self.error = Some(error.to_string());
+        self
+    }
+
+    /// Prints the record as a single line of JSON.
+    pub fn print(&self) {
+        println!("{}", serde_json::to_string(self).unwrap());
+    }
+}
+
+/// Hashes an input with 64-bit FNV-1a, so results can be matched to the input they came from.
+pub fn checksum(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fnv_checksums() {
+        assert_eq!(checksum(""), "cbf29ce484222325");
+        assert_eq!(checksum("a"), "af63dc4c8601ec8c");
+        assert_eq!(checksum("foobar"), "85944171f73967e8");
+    }
+
+    #[test]
+    fn records() {
+        let timed = Timed {
+            answer: Answer::Unsigned(142),
+            parse: Duration::from_nanos(10),
+            solve: Duration::from_nanos(20),
+            parse_heap: Some(64),
+            solve_heap: None,
+        };
+        let record = Record::new("2023", 1, Part::Two)
+            .input("a")
+            .solved(&timed);
+
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            "{\"year\":\"2023\",\"day\":1,\"part\":2,\"answer\":142,\"answer_type\":\"unsigned\",\
+                \"load_ns\":null,\"parse_ns\":10,\"solve_ns\":20,\
+                \"parse_peak_heap_bytes\":64,\"solve_peak_heap_bytes\":null,\
+                \"input_checksum\":\"af63dc4c8601ec8c\",\"error\":null}",
+        );
+
+        let record = Record::new("2023", 12, Part::Two).error("not implemented");
+
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            "{\"year\":\"2023\",\"day\":12,\"part\":2,\"answer\":null,\"answer_type\":null,\
+                \"load_ns\":null,\"parse_ns\":null,\"solve_ns\":null,\
+                \"parse_peak_heap_bytes\":null,\"solve_peak_heap_bytes\":null,\
+                \"input_checksum\":null,\"error\":\"not implemented\"}",
+        );
+    }
+}
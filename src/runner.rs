@@ -0,0 +1,125 @@
+use std::{any::Any, fmt, panic, time::{Duration, Instant}};
+
+use crate::{answers::{verify, verify_unsolved, Mode}, config::Format, input::get_input, output::Record, solutions::{Day, Part, Timed}};
+
+pub enum Outcome {
+    Solved(Timed),
+    NotImplemented,
+    Panicked(String),
+}
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Solved(timed) => write!(f, "{}", timed.answer),
+            Outcome::NotImplemented => write!(f, "not implemented"),
+            Outcome::Panicked(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+pub struct Run {
+    pub outcome: Outcome,
+    pub time: Duration,
+}
+
+pub fn run(day: &Day, input: &str, part: Part) -> Run {
+    let start = Instant::now();
+    let result = panic::catch_unwind(|| (day.run)(input, part));
+
+    match result {
+        Ok(timed) => Run {
+            time: timed.parse + timed.solve,
+            outcome: Outcome::Solved(timed),
+        },
+        Err(payload) => {
+            let outcome = match panic_message(&payload) {
+                message if message == "not yet implemented" => Outcome::NotImplemented,
+                message => Outcome::Panicked(message),
+            };
+            Run { outcome, time: start.elapsed() }
+        },
+    }
+}
+
+fn panic_message(payload: &Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+fn print_record(day: &Day, part: Part, input: &str, run: &Run) {
+    let record = Record::new(day.year, day.number(), part).input(input);
+
+    match &run.outcome {
+        Outcome::Solved(timed) => record.solved(timed).print(),
+        outcome => record.error(outcome).print(),
+    }
+}
+
+/// Runs every day, returning false if any answer failed verification.
+pub fn run_all(days: &[Day], mode: Option<Mode>, format: Format) -> bool {
+    if format == Format::Text {
+        println!("{:<8}{:<20}{:>12}  {:<20}{:>12}", "day", "part one", "time", "part two", "time");
+    }
+
+    let mut total = Duration::ZERO;
+    let mut verified = true;
+
+    for day in days {
+        let input = match get_input(day.year, day.name) {
+            Ok(input) => input,
+            Err(error) => {
+                match format {
+                    Format::Text => println!("{:<8}error: {error}", day.number()),
+                    Format::Json => for part in [Part::One, Part::Two] {
+                        Record::new(day.year, day.number(), part).error(&error).print();
+                    },
+                }
+                if let Some(Mode::Check) = mode {
+                    eprintln!("{} {}: {error}", day.year, day.name);
+                    verified = false;
+                }
+                continue;
+            },
+        };
+
+        let one = run(day, &input, Part::One);
+        let two = run(day, &input, Part::Two);
+        total += one.time + two.time;
+
+        if let Some(mode) = mode {
+            for (part, run) in [(Part::One, &one), (Part::Two, &two)] {
+                verified &= match &run.outcome {
+                    Outcome::Solved(timed) => verify(mode, day.year, day.name, part, &timed.answer),
+                    outcome => verify_unsolved(mode, day.year, day.name, part, outcome),
+                };
+            }
+        }
+
+        match format {
+            Format::Text => println!(
+                "{:<8}{:<20}{:>12}  {:<20}{:>12}",
+                day.number(),
+                one.outcome.to_string(),
+                format!("{:.2?}", one.time),
+                two.outcome.to_string(),
+                format!("{:.2?}", two.time),
+            ),
+            Format::Json => {
+                print_record(day, Part::One, &input, &one);
+                print_record(day, Part::Two, &input, &two);
+            },
+        }
+    }
+
+    if format == Format::Text {
+        println!("total {:.2?}", total);
+    }
+
+    verified
+}
+
@@ -0,0 +1,119 @@
+use std::{fs::{create_dir_all, read_to_string, write}, path::PathBuf};
+
+use crate::{config, examples, input::{get_input, get_puzzle_page}};
+
+const TEMPLATE: &str = include_str!("solutions/day_00.rs");
+
+fn solutions_dir() -> PathBuf {
+    config::get().path("src/solutions")
+}
+
+/// Points the template's tests at the new day's saved examples.
+fn fill_template(template: &str, year: &str, name: &str) -> String {
+    template.replace("example(\"0000\", \"day_00\"", &format!("example(\"{year}\", \"{name}\""))
+}
+
+/// Adds a module to a `days!` or `years!` list, keeping it in order.
+fn register(registry: &str, list_macro: &str, name: &str) -> String {
+    let (before, rest) = registry.split_once(&format!("{list_macro}!(\n")).unwrap();
+    let (list, after) = rest.split_once(");").unwrap();
+
+    let mut days = list.lines()
+        .map(|line| line.trim().trim_end_matches(',').to_string())
+        .filter(|day| !day.is_empty())
+        .collect::<Vec<String>>();
+
+    if !days.iter().any(|day| day == name) {
+        days.push(name.to_string());
+        days.sort();
+    }
+
+    let list = days.iter().map(|day| format!("    {day},\n")).collect::<String>();
+
+    format!("{before}{list_macro}!(\n{list});{after}")
+}
+
+/// Creates the `y<year>` module with an empty `days!` list and registers it.
+fn new_year(year: &str) {
+    let dir = solutions_dir().join(format!("y{year}"));
+    create_dir_all(&dir).unwrap();
+    write(dir.join("mod.rs"), format!("pub const YEAR: &str = \"{year}\";\n\ndays!(\n);\n")).unwrap();
+
+    let registry_path = solutions_dir().join("mod.rs");
+    let registry = read_to_string(&registry_path).unwrap();
+    write(&registry_path, register(&registry, "years", &format!("y{year}"))).unwrap();
+
+    println!("created {}", dir.display());
+}
+
+/// Creates `day_NN.rs` from the template, registers it and fetches its input and examples.
+pub fn new_day(year: &str, number: usize) {
+    let name = format!("day_{number:02}");
+    let dir = solutions_dir().join(format!("y{year}"));
+    let path = dir.join(format!("{name}.rs"));
+
+    if path.exists() {
+        panic!("{} already exists!", path.display());
+    }
+
+    let registry_path = dir.join("mod.rs");
+    if read_to_string(&registry_path).is_err() {
+        new_year(year);
+    }
+    let registry = read_to_string(&registry_path).unwrap();
+    write(&registry_path, register(&registry, "days", &name)).unwrap();
+
+    write(&path, fill_template(TEMPLATE, year, &name)).unwrap();
+    println!("created {}", path.display());
+
+    let input_dir = config::get().input_dir.join(year);
+
+    match get_input(year, &name) {
+        Ok(_) => println!("saved {}", input_dir.join(format!("{name}.txt")).display()),
+        Err(error) => println!("couldn't fetch input: {error}"),
+    }
+
+    let saved = match get_puzzle_page(year, &name) {
+        Ok(page) => examples::save(year, &name, &page),
+        Err(error) => return println!("couldn't fetch puzzle: {error}"),
+    };
+    println!("saved {} example(s)", saved.len());
+
+    if let Some((_, input, _)) = examples::load(year, &name).first() {
+        let test_path = input_dir.join(format!("{name}.test.txt"));
+        create_dir_all(&input_dir).unwrap();
+        write(&test_path, input).unwrap();
+        println!("saved {}", test_path.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_day() {
+        let registry = "days!(\n    day_01,\n    day_03,\n);\n\npub fn get_day";
+        let expected = "days!(\n    day_01,\n    day_02,\n    day_03,\n);\n\npub fn get_day";
+
+        assert_eq!(register(registry, "days", "day_02"), expected);
+        assert_eq!(register(expected, "days", "day_02"), expected);
+    }
+
+    #[test]
+    fn register_first_year() {
+        let registry = "years!(\n);\n";
+        let expected = "years!(\n    y2022,\n);\n";
+
+        assert_eq!(register(registry, "years", "y2022"), expected);
+    }
+
+    #[test]
+    fn fill_example_names() {
+        let source = fill_template(TEMPLATE, "2024", "day_07");
+
+        assert!(source.contains("example(\"2024\", \"day_07\", Part::One)"));
+        assert!(source.contains("example(\"2024\", \"day_07\", Part::Two)"));
+        assert!(!source.contains("day_00"));
+    }
+}
@@ -1,3 +1,22 @@
+use crate::solutions::{Answer, Solution};
+
+pub struct Solver;
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &&str) -> Answer {
+        solve_part_two(input).into()
+    }
+}
+
 pub fn solve_part_one(input: &str) -> usize {
     todo!()
 }
@@ -9,20 +28,19 @@ pub fn solve_part_two(input: &str) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const INPUT: &str = "";
+    use crate::{examples::example, solutions::Part};
 
     #[test]
     fn part_one() {
-        let expected = 0;
+        let (input, expected) = example("0000", "day_00", Part::One);
 
-        assert_eq!(solve_part_one(INPUT), expected);
+        assert_eq!(solve_part_one(&input).to_string(), expected);
     }
 
     #[test]
     fn part_two() {
-        let expected = 0;
+        let (input, expected) = example("0000", "day_00", Part::Two);
 
-        assert_eq!(solve_part_two(INPUT), expected);
+        assert_eq!(solve_part_two(&input).to_string(), expected);
     }
 }
@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::{fmt, str::FromStr, time::{Duration, Instant}};
+
+use crate::heap::measure;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(usize),
+    Signed(isize),
+    Text(String),
+}
+impl Answer {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Unsigned(_) => "unsigned",
+            Answer::Signed(_) => "signed",
+            Answer::Text(_) => "text",
+        }
+    }
+}
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Unsigned(n) => serializer.serialize_u64(*n as u64),
+            Answer::Signed(n) => serializer.serialize_i64(*n as i64),
+            Answer::Text(s) => serializer.serialize_str(s),
+        }
+    }
+}
+impl From<usize> for Answer {
+    fn from(value: usize) -> Answer {
+        Answer::Unsigned(value)
+    }
+}
+impl From<isize> for Answer {
+    fn from(value: isize) -> Answer {
+        Answer::Signed(value)
+    }
+}
+impl From<String> for Answer {
+    fn from(value: String) -> Answer {
+        Answer::Text(value)
+    }
+}
+impl From<&str> for Answer {
+    fn from(value: &str) -> Answer {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Part {
+    One,
+    Two,
+}
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Part, String> {
+        match value {
+            "1" | "one" => Ok(Part::One),
+            "2" | "two" => Ok(Part::Two),
+            _ => Err(format!("unknown part {value}")),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+pub trait Solution {
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Self::Parsed<'_>;
+    fn part_one(parsed: &Self::Parsed<'_>) -> Answer;
+    fn part_two(parsed: &Self::Parsed<'_>) -> Answer;
+}
+
+pub struct Timed {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+    /// The most heap parsing used, with the `alloc-stats` feature.
+    pub parse_heap: Option<usize>,
+    /// The most heap solving used on top of the parsed input, with the `alloc-stats` feature.
+    pub solve_heap: Option<usize>,
+}
+
+pub fn run<S: Solution>(input: &str, part: Part) -> Timed {
+    let start = Instant::now();
+    let (parsed, parse_heap) = measure(|| S::parse(input));
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let (answer, solve_heap) = measure(|| match part {
+        Part::One => S::part_one(&parsed),
+        Part::Two => S::part_two(&parsed),
+    });
+    let solve = start.elapsed();
+
+    Timed {
+        answer,
+        parse,
+        solve,
+        parse_heap,
+        solve_heap,
+    }
+}
+
+pub struct Day {
+    pub year: &'static str,
+    pub name: &'static str,
+    pub run: fn(&str, Part) -> Timed,
+}
+impl Day {
+    pub fn number(&self) -> usize {
+        self.name.trim_start_matches("day_").parse().unwrap()
+    }
+}
+
+/// Declares a year's day modules and lists them in its `DAYS`, using the module's `YEAR`.
+macro_rules! days {
+    ($($day:ident),* $(,)?) => {
+        $(pub mod $day;)*
+
+        pub const DAYS: &[$crate::solutions::Day] = &[
+            $($crate::solutions::Day {
+                year: YEAR,
+                name: stringify!($day),
+                run: $crate::solutions::run::<$day::Solver>,
+            },)*
+        ];
+    };
+}
+
+macro_rules! years {
+    ($($year:ident),* $(,)?) => {
+        $(pub mod $year;)*
+
+        pub const YEARS: &[&[Day]] = &[
+            $($year::DAYS,)*
+        ];
+    };
+}
+
+years!(
+    y2023,
+);
+
+pub fn latest_year() -> &'static str {
+    YEARS.last().unwrap().first().unwrap().year
+}
+
+pub fn get_days(year: &str) -> Option<&'static [Day]> {
+    YEARS.iter().find(|days| days.first().is_some_and(|day| day.year == year)).copied()
+}
+
+pub fn get_day(year: &str, number: usize) -> Option<&'static Day> {
+    get_days(year)?.iter().find(|day| day.number() == number)
+}
@@ -1,3 +1,5 @@
+use crate::solutions::{Answer, Solution};
+
 pub fn solve_part_one(input: &str) -> usize {
     input.lines()
         .fold(0, |sum, line| {
@@ -72,38 +74,39 @@ fn word_to_digit(word: &str) -> usize {
     }
 }
 
+pub struct Solver;
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &&str) -> Answer {
+        solve_part_two(input).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const INPUT: &str = "\
-        1abc2\n\
-        pqr3stu8vwx\n\
-        a1b2c3d4e5f\n\
-        treb7uchet\n\
-    ";
-
-    const INPUT_TWO: &str = "\
-        two1nine\n\
-        eightwothree\n\
-        abcone2threexyz\n\
-        xtwone3four\n\
-        4nineeightseven2\n\
-        zoneight234\n\
-        7pqrstsixteen\n\
-    ";
+    use crate::{examples::example, solutions::Part};
 
     #[test]
     fn part_one() {
-        let expected = 142;
+        let (input, expected) = example("2023", "day_01", Part::One);
 
-        assert_eq!(solve_part_one(INPUT), expected);
+        assert_eq!(solve_part_one(&input).to_string(), expected);
     }
 
     #[test]
     fn part_two() {
-        let expected = 281;
+        let (input, expected) = example("2023", "day_01", Part::Two);
 
-        assert_eq!(solve_part_two(INPUT_TWO), expected);
+        assert_eq!(solve_part_two(&input).to_string(), expected);
     }
 }
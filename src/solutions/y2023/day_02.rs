@@ -1,17 +1,19 @@
 use itertools::Itertools;
 
+use crate::solutions::{Answer, Solution};
+
 mod totals {
     pub const RED: usize = 12;
     pub const GREEN: usize = 13;
     pub const BLUE: usize = 14;
 }
 
-struct Game {
+pub struct Game {
     id: usize,
     sets: Vec<Set>,
 }
 impl Game {
-    fn new(line: &str) -> Game {
+    pub fn new(line: &str) -> Game {
         let (id, sets) = line.split(": ").collect_tuple().unwrap();
 
         let id = id.replace("Game ", "").parse::<usize>().unwrap();
@@ -100,18 +102,31 @@ impl Set {
     }
 }
 
-pub fn solve_part_one(input: &str) -> usize {
-    input.lines().fold(0, |sum, line| {
-        let game = Game::new(line);
+pub struct Solver;
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Vec<Game> {
+        input.lines().map(Game::new).collect()
+    }
+
+    fn part_one(games: &Vec<Game>) -> Answer {
+        solve_part_one(games).into()
+    }
+
+    fn part_two(games: &Vec<Game>) -> Answer {
+        solve_part_two(games).into()
+    }
+}
 
+pub fn solve_part_one(games: &[Game]) -> usize {
+    games.iter().fold(0, |sum, game| {
         sum + if game.is_possible() { game.id } else { 0 }
     })
 }
 
-pub fn solve_part_two(input: &str) -> usize {
-    input.lines().fold(0, |sum, line| {
-        let game = Game::new(line);
-
+pub fn solve_part_two(games: &[Game]) -> usize {
+    games.iter().fold(0, |sum, game| {
         let min_set = game.find_min();
 
         sum + min_set.get_power()
@@ -121,26 +136,19 @@ pub fn solve_part_two(input: &str) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const INPUT: &str = "\
-        Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
-        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n\
-        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n\
-        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n\
-        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green\n\
-    ";
+    use crate::{examples::example, solutions::Part};
 
     #[test]
     fn part_one() {
-        let expected = 8;
+        let (input, expected) = example("2023", "day_02", Part::One);
 
-        assert_eq!(solve_part_one(INPUT), expected);
+        assert_eq!(solve_part_one(&Solver::parse(&input)).to_string(), expected);
     }
 
     #[test]
     fn part_two() {
-        let expected = 2286;
+        let (input, expected) = example("2023", "day_02", Part::Two);
 
-        assert_eq!(solve_part_two(INPUT), expected);
+        assert_eq!(solve_part_two(&Solver::parse(&input)).to_string(), expected);
     }
 }
@@ -0,0 +1,149 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{grid::{Grid, Pos}, solutions::{Answer, Solution}};
+
+#[derive(Debug)]
+pub enum Cell {
+    Digit(char),
+    Symbol,
+    Gear,
+    Empty,
+}
+impl From<char> for Cell {
+    fn from(c: char) -> Cell {
+        match (c.is_numeric(), c) {
+            (true, _) => Cell::Digit(c),
+            (false, '.') => Cell::Empty,
+            (false, '*') => Cell::Gear,
+            (false, _) => Cell::Symbol,
+        }
+    }
+}
+
+fn near_symbol(grid: &Grid<Cell>, pos: Pos) -> bool {
+    grid.neighbours_with_diagonals(pos)
+        .any(|neighbour| matches!(grid[neighbour], Cell::Symbol | Cell::Gear))
+}
+
+fn adjacent_gears(grid: &Grid<Cell>, pos: Pos) -> Vec<Pos> {
+    grid.neighbours_with_diagonals(pos)
+        .filter(|&neighbour| matches!(grid[neighbour], Cell::Gear))
+        .collect()
+}
+
+pub struct Solver;
+impl Solution for Solver {
+    type Parsed<'a> = Grid<Cell>;
+
+    fn parse(input: &str) -> Grid<Cell> {
+        Grid::parse(input)
+    }
+
+    fn part_one(grid: &Grid<Cell>) -> Answer {
+        solve_part_one(grid).into()
+    }
+
+    fn part_two(grid: &Grid<Cell>) -> Answer {
+        solve_part_two(grid).into()
+    }
+}
+
+pub fn solve_part_one(grid: &Grid<Cell>) -> usize {
+    let mut sum = 0;
+
+    for (x, row) in grid.rows().enumerate() {
+        let mut number = String::new();
+        let mut is_adjacent = false;
+
+        for (y, cell) in row.iter().enumerate() {
+            match cell {
+                Cell::Digit(digit) => {
+                    number.push(*digit);
+
+                    if near_symbol(grid, Pos::new(x, y)) {
+                        is_adjacent = true;
+                    }
+                },
+                _ => {
+                    if is_adjacent && !number.is_empty() {
+                        sum += number.parse::<usize>().unwrap();
+                    }
+                    number = String::new();
+                    is_adjacent = false;
+                }
+            }
+        }
+
+        if is_adjacent && !number.is_empty() {
+            sum += number.parse::<usize>().unwrap();
+        }
+    }
+
+    sum
+}
+
+pub fn solve_part_two(grid: &Grid<Cell>) -> usize {
+    let mut gears = HashMap::<Pos, HashSet<usize>>::new();
+    let mut numbers = HashMap::<usize, usize>::new();
+
+    let mut number = String::new();
+    let mut number_id = 0;
+
+    for (x, row) in grid.rows().enumerate() {
+        for (y, cell) in row.iter().enumerate() {
+            match cell {
+                Cell::Digit(digit) => {
+                    number.push(*digit);
+
+                    for gear in adjacent_gears(grid, Pos::new(x, y)) {
+                        let adjacent_numbers = gears.entry(gear).or_default();
+                        adjacent_numbers.insert(number_id);
+                    }
+                },
+                _ => {
+                    if !number.is_empty() {
+                        numbers.insert(number_id, number.parse().unwrap());
+                        number = String::new();
+                        number_id += 1;
+                    }
+                }
+            }
+        }
+
+        if !number.is_empty() {
+            numbers.insert(number_id, number.parse().unwrap());
+        }
+        number = String::new();
+        number_id += 1;
+    }
+
+    gears.into_values().map(|adjacent_numbers| {
+        if adjacent_numbers.len() == 2 {
+            adjacent_numbers.into_iter()
+                .map(|number_id| numbers.get(&number_id).unwrap())
+                .product()
+        } else {
+            0
+        }
+    }).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{examples::example, solutions::Part};
+
+    #[test]
+    fn part_one() {
+        let (input, expected) = example("2023", "day_03", Part::One);
+
+        assert_eq!(solve_part_one(&Solver::parse(&input)).to_string(), expected);
+    }
+
+    #[test]
+    fn part_two() {
+        let (input, expected) = example("2023", "day_03", Part::Two);
+
+        assert_eq!(solve_part_two(&Solver::parse(&input)).to_string(), expected);
+    }
+}
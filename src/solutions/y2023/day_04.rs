@@ -1,3 +1,5 @@
+use crate::solutions::{Answer, Solution};
+
 pub fn solve_part_one(input: &str) -> usize {
     input.lines().map(|line| {
         let (_, card) = line.split_once(": ").unwrap();
@@ -56,30 +58,39 @@ pub fn solve_part_two(input: &str) -> usize {
     cards.into_iter().map(|card| card.copies).sum()
 }
 
+pub struct Solver;
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &&str) -> Answer {
+        solve_part_two(input).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const INPUT: &str = "\
-        Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\n\
-        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19\n\
-        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1\n\
-        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83\n\
-        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36\n\
-        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11\n\
-    ";
+    use crate::{examples::example, solutions::Part};
 
     #[test]
     fn part_one() {
-        let expected = 13;
+        let (input, expected) = example("2023", "day_04", Part::One);
 
-        assert_eq!(solve_part_one(INPUT), expected);
+        assert_eq!(solve_part_one(&input).to_string(), expected);
     }
 
     #[test]
     fn part_two() {
-        let expected = 30;
+        let (input, expected) = example("2023", "day_04", Part::Two);
 
-        assert_eq!(solve_part_two(INPUT), expected);
+        assert_eq!(solve_part_two(&input).to_string(), expected);
     }
 }
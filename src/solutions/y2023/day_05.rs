@@ -0,0 +1,471 @@
+use itertools::Itertools;
+use std::{collections::{HashMap, VecDeque}, fmt};
+
+use crate::{interval::IntervalSet, solutions::{Answer, Solution}};
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<usize>,
+    maps: Vec<Map>,
+}
+impl Almanac {
+    pub fn new(input: &str) -> Almanac {
+        let chunks = input.split("\n\n").collect::<Vec<&str>>();
+        let (seeds, maps) = chunks.split_at(1);
+
+        let seeds = seeds
+            .first().unwrap()
+            .replace("seeds: ", "")
+            .split_whitespace()
+            .map(|seed| seed.parse::<usize>().unwrap())
+            .collect::<Vec<usize>>();
+
+        let maps = maps.iter().map(|map| {
+            let lines = map.lines().collect::<Vec<&str>>();
+
+            let (types, ranges) = lines.split_at(1);
+
+            let types = types.first().unwrap().replace(" map:", "");
+            let (source, destination) = types.split_once("-to-").unwrap();
+            let (source, destination) = (source.to_string(), destination.to_string());
+
+            let ranges = ranges.iter()
+                .map(|range| range
+                    .split_whitespace()
+                    .map(|n| n.parse::<usize>().unwrap())
+                    .collect_tuple().unwrap()
+                )
+                .collect::<Vec<(usize, usize, usize)>>();
+
+            Map {
+                source,
+                destination,
+                ranges,
+            }
+        }).collect::<Vec<Map>>();
+
+        Almanac {
+            seeds,
+            maps,
+        }
+    }
+
+    /// Every category named in a map header, in the order they first appear.
+    fn names(&self) -> Vec<&str> {
+        self.maps.iter()
+            .flat_map(|map| [map.source.as_str(), map.destination.as_str()])
+            .unique()
+            .collect()
+    }
+
+    fn maps_from<'a>(&'a self, category: &'a str) -> impl Iterator<Item = &'a Map> + 'a {
+        self.maps.iter().filter(move |map| map.source == category)
+    }
+
+    /// Lists the categories so every map goes from an earlier category to a later one.
+    pub fn categories(&self) -> Result<Vec<&str>, AlmanacError> {
+        self.traverse(self.names())
+    }
+
+    /// Orders the categories reachable from `starts` like `categories`, failing if any of
+    /// them lead round in a cycle.
+    fn traverse<'a>(&'a self, starts: impl IntoIterator<Item = &'a str>) -> Result<Vec<&'a str>, AlmanacError> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Mark {
+            Visiting,
+            Done,
+        }
+
+        fn visit<'a>(
+            almanac: &'a Almanac,
+            category: &'a str,
+            marks: &mut HashMap<&'a str, Mark>,
+            stack: &mut Vec<&'a str>,
+            order: &mut Vec<&'a str>,
+        ) -> Result<(), AlmanacError> {
+            match marks.get(category) {
+                Some(Mark::Done) => return Ok(()),
+                Some(Mark::Visiting) => {
+                    let start = stack.iter().position(|&visiting| visiting == category).unwrap();
+                    let cycle = stack[start..].iter().chain([&category]).map(|name| name.to_string()).collect();
+                    return Err(AlmanacError::Cycle(cycle));
+                },
+                None => {},
+            }
+
+            marks.insert(category, Mark::Visiting);
+            stack.push(category);
+            for map in almanac.maps_from(category) {
+                visit(almanac, &map.destination, marks, stack, order)?;
+            }
+            stack.pop();
+            marks.insert(category, Mark::Done);
+            order.push(category);
+
+            Ok(())
+        }
+
+        let mut marks = HashMap::new();
+        let mut order = Vec::new();
+        for category in starts {
+            visit(self, category, &mut marks, &mut Vec::new(), &mut order)?;
+        }
+
+        order.reverse();
+        Ok(order)
+    }
+
+    /// Finds the shortest chain of maps leading from `source` to `destination`. Only the
+    /// categories reachable from `source` are checked for cycles, so a cycle elsewhere in the
+    /// almanac doesn't get in the way.
+    pub fn path<'a>(&'a self, source: &'a str, destination: &'a str) -> Result<Vec<&'a Map>, AlmanacError> {
+        let names = self.names();
+
+        for category in [source, destination] {
+            if !names.contains(&category) {
+                return Err(AlmanacError::UnknownCategory(category.to_string()));
+            }
+        }
+
+        let mut previous: HashMap<&str, &Map> = HashMap::new();
+        let mut queue = VecDeque::from([source]);
+
+        while let Some(category) = queue.pop_front() {
+            if category == destination {
+                let mut path = Vec::new();
+                let mut step = previous.get(destination);
+                while let Some(&map) = step {
+                    path.push(map);
+                    step = previous.get(map.source.as_str());
+                }
+                path.reverse();
+
+                self.traverse([source])?;
+                return Ok(path);
+            }
+
+            for map in self.maps_from(category) {
+                if map.destination != source && !previous.contains_key(map.destination.as_str()) {
+                    previous.insert(&map.destination, map);
+                    queue.push_back(&map.destination);
+                }
+            }
+        }
+
+        Err(AlmanacError::NoPath {
+            source: source.to_string(),
+            destination: destination.to_string(),
+        })
+    }
+
+    /// Composes the maps from `source` to `destination` into a single map.
+    pub fn collapse(&self, source: &str, destination: &str) -> Result<Map, AlmanacError> {
+        let identity = Map {
+            source: source.to_string(),
+            destination: source.to_string(),
+            ranges: Vec::new(),
+        };
+
+        Ok(self.path(source, destination)?.into_iter().fold(identity, |map, next| map.compose(next)))
+    }
+
+    /// Returns every seed that ends up at `location`.
+    pub fn seeds_reaching(&self, location: usize) -> Result<IntervalSet<usize>, AlmanacError> {
+        let map = self.collapse("seed", "location")?;
+
+        Ok(map.preimage(&IntervalSet::from(location..(location + 1))))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AlmanacError {
+    UnknownCategory(String),
+    NoPath { source: String, destination: String },
+    Cycle(Vec<String>),
+}
+impl fmt::Display for AlmanacError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlmanacError::UnknownCategory(category) => write!(f, "no map mentions the {category} category"),
+            AlmanacError::NoPath { source, destination } => write!(f, "no chain of maps leads from {source} to {destination}"),
+            AlmanacError::Cycle(cycle) => write!(f, "maps form a cycle: {}", cycle.join(" -> ")),
+        }
+    }
+}
+impl std::error::Error for AlmanacError {}
+
+fn shift(value: usize, offset: isize) -> usize {
+    value.checked_add_signed(offset).unwrap()
+}
+
+fn shift_set(values: &IntervalSet<usize>, offset: isize) -> IntervalSet<usize> {
+    match offset >= 0 {
+        true => values.shift_up(offset.unsigned_abs()),
+        false => values.shift_down(offset.unsigned_abs()),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Map {
+    source: String,
+    destination: String,
+    ranges: Vec<(usize, usize, usize)>,
+}
+impl Map {
+    pub fn map(&self, value: usize) -> usize {
+        let range = self.ranges.iter()
+            .find(|(_dest, src, len)|
+                value >= *src && value < src + len
+            );
+
+        match range {
+            Some((dest, src, _len)) => dest + (value - src),
+            None => value,
+        }
+    }
+
+    /// Maps a whole set of values, splitting its ranges wherever they cross the edge of one of
+    /// the map's ranges.
+    pub fn map_set(&self, values: &IntervalSet<usize>) -> IntervalSet<usize> {
+        let mut unmapped = values.clone();
+        let mut mapped = IntervalSet::new();
+
+        for &(dest, src, len) in self.ranges.iter() {
+            let source = IntervalSet::from(src..(src + len));
+            let hit = unmapped.intersection(&source);
+            unmapped = unmapped.difference(&source);
+
+            mapped = mapped.union(&shift_set(&hit, dest as isize - src as isize));
+        }
+
+        mapped.union(&unmapped)
+    }
+
+    /// Splits every possible value into `(start, end, offset)` pieces, including the gaps
+    /// between ranges, which map to themselves.
+    fn pieces(&self) -> Vec<(usize, usize, isize)> {
+        let mut pieces = Vec::new();
+        let mut next = 0;
+
+        for &(dest, src, len) in self.ranges.iter().sorted_by_key(|(_dest, src, _len)| src) {
+            if next < src {
+                pieces.push((next, src, 0));
+            }
+            pieces.push((src, src + len, dest as isize - src as isize));
+            next = src + len;
+        }
+
+        if next < usize::MAX {
+            pieces.push((next, usize::MAX, 0));
+        }
+
+        pieces
+    }
+
+    /// Returns the map that applies this map and then `next`.
+    pub fn compose(&self, next: &Map) -> Map {
+        let next_pieces = next.pieces();
+
+        let ranges = self.pieces().into_iter()
+            .flat_map(|(start, end, offset)| {
+                let (image_start, image_end) = (shift(start, offset), shift(end, offset));
+
+                next_pieces.iter().filter_map(move |&(next_start, next_end, next_offset)| {
+                    let (from, to) = (image_start.max(next_start), image_end.min(next_end));
+                    let src = shift(from, -offset);
+
+                    (from < to && offset + next_offset != 0)
+                        .then(|| (shift(src, offset + next_offset), src, to - from))
+                })
+            })
+            .collect();
+
+        Map {
+            source: self.source.clone(),
+            destination: next.destination.clone(),
+            ranges,
+        }
+    }
+
+    /// Returns every value that maps into `values`. Several values can map to the same place,
+    /// so this is a set rather than an inverse map.
+    pub fn preimage(&self, values: &IntervalSet<usize>) -> IntervalSet<usize> {
+        self.pieces().into_iter()
+            .map(|(start, end, offset)| {
+                let image = IntervalSet::from(shift(start, offset)..shift(end, offset));
+                shift_set(&values.intersection(&image), -offset)
+            })
+            .fold(IntervalSet::new(), |preimage, values| preimage.union(&values))
+    }
+}
+
+pub struct Solver;
+impl Solution for Solver {
+    type Parsed<'a> = Almanac;
+
+    fn parse(input: &str) -> Almanac {
+        Almanac::new(input)
+    }
+
+    fn part_one(almanac: &Almanac) -> Answer {
+        solve_part_one(almanac).into()
+    }
+
+    fn part_two(almanac: &Almanac) -> Answer {
+        solve_part_two(almanac).into()
+    }
+}
+
+pub fn solve_part_one(almanac: &Almanac) -> usize {
+    let map = almanac.collapse("seed", "location").unwrap_or_else(|error| panic!("{error}"));
+
+    almanac.seeds.iter().map(|&seed| map.map(seed)).min().unwrap()
+}
+
+pub fn solve_part_two(almanac: &Almanac) -> usize {
+    let seeds = almanac.seeds
+        .chunks(2)
+        .map(|pair| pair[0]..(pair[0] + pair[1]))
+        .collect::<IntervalSet<usize>>();
+
+    let map = almanac.collapse("seed", "location").unwrap_or_else(|error| panic!("{error}"));
+
+    map.map_set(&seeds).min().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{examples::example, solutions::Part};
+
+    fn example_almanac() -> Almanac {
+        Solver::parse(&example("2023", "day_05", Part::One).0)
+    }
+
+    #[test]
+    fn part_one() {
+        let (input, expected) = example("2023", "day_05", Part::One);
+
+        assert_eq!(solve_part_one(&Solver::parse(&input)).to_string(), expected);
+    }
+
+    #[test]
+    fn part_two() {
+        let (input, expected) = example("2023", "day_05", Part::Two);
+
+        assert_eq!(solve_part_two(&Solver::parse(&input)).to_string(), expected);
+    }
+
+    #[test]
+    fn map_sets() {
+        let almanac = example_almanac();
+        let seed_to_soil = &almanac.maps[0];
+
+        assert_eq!(seed_to_soil.map_set(&IntervalSet::from(45..100)), IntervalSet::from(45..100));
+        assert_eq!(seed_to_soil.map_set(&IntervalSet::from(10..20)), IntervalSet::from(10..20));
+        assert_eq!(seed_to_soil.map_set(&IntervalSet::from(98..100)), IntervalSet::from(50..52));
+        assert_eq!(seed_to_soil.map_set(&IntervalSet::from(96..99)).ranges(), [50..51, 98..100]);
+    }
+
+    #[test]
+    fn compose_maps() {
+        let almanac = example_almanac();
+        let map = almanac.collapse("seed", "location").unwrap();
+
+        assert_eq!(almanac.seeds.iter().map(|&seed| map.map(seed)).collect::<Vec<usize>>(), [82, 43, 86, 35]);
+
+        for value in 0..200 {
+            let stepwise = almanac.maps.iter().fold(value, |value, map| map.map(value));
+            assert_eq!(map.map(value), stepwise, "{value}");
+        }
+    }
+
+    #[test]
+    fn seeds_reaching() {
+        let almanac = example_almanac();
+        let map = almanac.collapse("seed", "location").unwrap();
+
+        let seeds = almanac.seeds_reaching(46).unwrap();
+
+        assert!(seeds.contains(82));
+        for seed in 0..200 {
+            assert_eq!(seeds.contains(seed), map.map(seed) == 46, "{seed}");
+        }
+    }
+
+    #[test]
+    fn category_graph() {
+        let almanac = example_almanac();
+
+        assert_eq!(
+            almanac.categories().unwrap(),
+            ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"],
+        );
+        assert_eq!(almanac.path("water", "temperature").unwrap().len(), 2);
+        assert_eq!(almanac.collapse("soil", "soil").unwrap().map(17), 17);
+        assert_eq!(almanac.collapse("soil", "fertilizer").unwrap().map(81), 81);
+        assert_eq!(
+            almanac.path("location", "seed").unwrap_err(),
+            AlmanacError::NoPath { source: "location".to_string(), destination: "seed".to_string() },
+        );
+        assert_eq!(almanac.path("seed", "fuel").unwrap_err(), AlmanacError::UnknownCategory("fuel".to_string()));
+    }
+
+    #[test]
+    fn branching_categories() {
+        let almanac = Solver::parse("\
+            seeds: 1\n\
+            \n\
+            seed-to-soil map:\n\
+            10 0 5\n\
+            \n\
+            seed-to-water map:\n\
+            20 0 5\n\
+            \n\
+            soil-to-location map:\n\
+            100 10 5\n\
+            \n\
+            water-to-light map:\n\
+            30 20 5\n\
+        ");
+
+        assert_eq!(almanac.categories().unwrap(), ["seed", "water", "light", "soil", "location"]);
+        assert_eq!(almanac.collapse("seed", "location").unwrap().map(3), 103);
+        assert_eq!(almanac.collapse("seed", "light").unwrap().map(3), 33);
+    }
+
+    #[test]
+    fn cycles() {
+        let almanac = Solver::parse("\
+            seeds: 1\n\
+            \n\
+            seed-to-soil map:\n\
+            \n\
+            soil-to-water map:\n\
+            \n\
+            water-to-soil map:\n\
+        ");
+
+        let error = almanac.path("seed", "water").unwrap_err();
+
+        assert_eq!(error.to_string(), "maps form a cycle: soil -> water -> soil");
+    }
+
+    #[test]
+    fn cycles_off_the_path() {
+        let almanac = Solver::parse("\
+            seeds: 1\n\
+            \n\
+            seed-to-soil map:\n\
+            10 0 5\n\
+            \n\
+            water-to-light map:\n\
+            \n\
+            light-to-water map:\n\
+        ");
+
+        assert_eq!(almanac.collapse("seed", "soil").unwrap().map(3), 13);
+        assert!(matches!(almanac.categories(), Err(AlmanacError::Cycle(_))));
+        assert!(matches!(almanac.path("water", "light"), Err(AlmanacError::Cycle(_))));
+    }
+}
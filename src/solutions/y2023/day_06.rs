@@ -1,5 +1,7 @@
 use std::{fmt, iter, str};
 
+use crate::solutions::{Answer, Solution};
+
 pub fn solve_part_one(input: &str) -> usize {
     let mut lines = input.lines();
     let times = parse_line::<usize>(lines.next());
@@ -37,26 +39,39 @@ pub fn solve_part_two(input: &str) -> usize {
     (r1 - r2).unsigned_abs() + 1
 }
 
+pub struct Solver;
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &&str) -> Answer {
+        solve_part_two(input).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const INPUT: &str = "\
-        Time:      7  15   30\n\
-        Distance:  9  40  200\n\
-    ";
+    use crate::{examples::example, solutions::Part};
 
     #[test]
     fn part_one() {
-        let expected = 288;
+        let (input, expected) = example("2023", "day_06", Part::One);
 
-        assert_eq!(solve_part_one(INPUT), expected);
+        assert_eq!(solve_part_one(&input).to_string(), expected);
     }
 
     #[test]
     fn part_two() {
-        let expected = 71503;
+        let (input, expected) = example("2023", "day_06", Part::Two);
 
-        assert_eq!(solve_part_two(INPUT), expected);
+        assert_eq!(solve_part_two(&input).to_string(), expected);
     }
 }
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crate::solutions::{Answer, Solution};
+
 #[derive(Debug, Eq, PartialEq)]
 struct Hand {
     cards: Vec<char>,
@@ -196,29 +198,39 @@ fn parse_two(input: &str) -> Vec<HandTwo> {
     }).collect()
 }
 
+pub struct Solver;
+impl Solution for Solver {
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &&str) -> Answer {
+        solve_part_two(input).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const INPUT: &str = "\
-        32T3K 765\n\
-        T55J5 684\n\
-        KK677 28\n\
-        KTJJT 220\n\
-        QQQJA 483\n\
-    ";
+    use crate::{examples::example, solutions::Part};
 
     #[test]
     fn part_one() {
-        let expected = 6440;
+        let (input, expected) = example("2023", "day_07", Part::One);
 
-        assert_eq!(solve_part_one(INPUT), expected);
+        assert_eq!(solve_part_one(&input).to_string(), expected);
     }
 
     #[test]
     fn part_two() {
-        let expected = 5905;
+        let (input, expected) = example("2023", "day_07", Part::Two);
 
-        assert_eq!(solve_part_two(INPUT), expected);
+        assert_eq!(solve_part_two(&input).to_string(), expected);
     }
 }
@@ -1,6 +1,8 @@
 use num::integer::lcm;
 
-struct Map<'a> {
+use crate::solutions::{Answer, Solution};
+
+pub struct Map<'a> {
     directions: Vec<Direction>,
     nodes: Vec<Node<'a>>,
 }
@@ -34,16 +36,31 @@ impl Node<'_> {
     }
 }
 
-pub fn solve_part_one(input: &str) -> usize {
-    let map = parse(input);
+pub struct Solver;
+impl Solution for Solver {
+    type Parsed<'a> = Map<'a>;
+
+    fn parse(input: &str) -> Map<'_> {
+        parse(input)
+    }
+
+    fn part_one(map: &Map) -> Answer {
+        solve_part_one(map).into()
+    }
+
+    fn part_two(map: &Map) -> Answer {
+        solve_part_two(map).into()
+    }
+}
 
+pub fn solve_part_one(map: &Map) -> usize {
     const START: &str = "AAA";
     const END: &str = "ZZZ";
 
-    count_steps(&map, START, |id| id == END)
+    count_steps(map, START, |id| id == END)
 }
 
-fn parse(input: &str) -> Map {
+fn parse(input: &str) -> Map<'_> {
     let (directions, nodes) = input.split_once("\n\n").unwrap();
 
     let directions = directions.chars().map(Direction::from).collect();
@@ -81,12 +98,10 @@ fn count_steps(map: &Map, start: &str, done: fn(&str) -> bool) -> usize {
     steps
 }
 
-pub fn solve_part_two(input: &str) -> usize {
-    let map = parse(input);
-
+pub fn solve_part_two(map: &Map) -> usize {
     let step_counts = map.nodes.iter()
         .filter(|n| n.id.ends_with('A'))
-        .map(|node| count_steps(&map, node.id, |id| id.ends_with('Z')))
+        .map(|node| count_steps(map, node.id, |id| id.ends_with('Z')))
         .collect::<Vec<usize>>();
 
     find_lcm(&step_counts)
@@ -103,58 +118,27 @@ fn find_lcm(numbers: &[usize]) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const INPUT_ONE_A: &str = "\
-        RL\n\
-        \n\
-        AAA = (BBB, CCC)\n\
-        BBB = (DDD, EEE)\n\
-        CCC = (ZZZ, GGG)\n\
-        DDD = (DDD, DDD)\n\
-        EEE = (EEE, EEE)\n\
-        GGG = (GGG, GGG)\n\
-        ZZZ = (ZZZ, ZZZ)\n\
-    ";
-
-    const INPUT_ONE_B: &str = "\
-        LLR\n\
-        \n\
-        AAA = (BBB, BBB)\n\
-        BBB = (AAA, ZZZ)\n\
-        ZZZ = (ZZZ, ZZZ)\n\
-    ";
-
-    const INPUT_TWO: &str = "\
-        LR\n\
-        \n\
-        11A = (11B, XXX)\n\
-        11B = (XXX, 11Z)\n\
-        11Z = (11B, XXX)\n\
-        22A = (22B, XXX)\n\
-        22B = (22C, 22C)\n\
-        22C = (22Z, 22Z)\n\
-        22Z = (22B, 22B)\n\
-        XXX = (XXX, XXX)\n\
-    ";
+    use crate::{input::get_test_input, examples::example, solutions::Part};
 
     #[test]
     fn part_one_a() {
+        let input = get_test_input("2023", "day_08", Some("a")).unwrap();
         let expected = 2;
 
-        assert_eq!(solve_part_one(INPUT_ONE_A), expected);
+        assert_eq!(solve_part_one(&Solver::parse(&input)), expected);
     }
 
     #[test]
     fn part_one_b() {
-        let expected = 6;
+        let (input, expected) = example("2023", "day_08", Part::One);
 
-        assert_eq!(solve_part_one(INPUT_ONE_B), expected);
+        assert_eq!(solve_part_one(&Solver::parse(&input)).to_string(), expected);
     }
 
     #[test]
     fn part_two() {
-        let expected = 6;
+        let (input, expected) = example("2023", "day_08", Part::Two);
 
-        assert_eq!(solve_part_two(INPUT_TWO), expected);
+        assert_eq!(solve_part_two(&Solver::parse(&input)).to_string(), expected);
     }
 }
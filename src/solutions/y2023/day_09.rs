@@ -0,0 +1,66 @@
+use crate::solutions::{Answer, Solution};
+
+pub struct Solver;
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Vec<isize>>;
+
+    fn parse(input: &str) -> Vec<Vec<isize>> {
+        input.lines().map(|sequence| sequence
+            .split_whitespace()
+            .map(|n| n.parse().unwrap())
+            .collect()
+        ).collect()
+    }
+
+    fn part_one(sequences: &Vec<Vec<isize>>) -> Answer {
+        solve_part_one(sequences).into()
+    }
+
+    fn part_two(sequences: &Vec<Vec<isize>>) -> Answer {
+        solve_part_two(sequences).into()
+    }
+}
+
+pub fn solve_part_one(sequences: &[Vec<isize>]) -> isize {
+    sequences.iter().map(|sequence| get_next(sequence)).sum()
+}
+
+fn get_next(sequence: &[isize]) -> isize {
+    let diffs = sequence.windows(2).map(|pair| pair[1] - pair[0]).collect::<Vec<isize>>();
+
+    if diffs.iter().all(|diff| *diff == 0) {
+        *sequence.last().unwrap()
+    } else {
+        sequence.last().unwrap() + get_next(&diffs)
+    }
+}
+
+pub fn solve_part_two(sequences: &[Vec<isize>]) -> isize {
+    sequences.iter().map(|sequence| {
+        let mut sequence = sequence.clone();
+
+        sequence.reverse();
+        
+        get_next(&sequence)
+    }).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{examples::example, solutions::Part};
+
+    #[test]
+    fn part_one() {
+        let (input, expected) = example("2023", "day_09", Part::One);
+
+        assert_eq!(solve_part_one(&Solver::parse(&input)).to_string(), expected);
+    }
+
+    #[test]
+    fn part_two() {
+        let (input, expected) = example("2023", "day_09", Part::Two);
+
+        assert_eq!(solve_part_two(&Solver::parse(&input)).to_string(), expected);
+    }
+}
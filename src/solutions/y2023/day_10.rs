@@ -0,0 +1,206 @@
+use crate::{grid::{Direction, Grid, Pos}, solutions::{Answer, Solution}};
+
+#[derive(Clone)]
+pub struct Sketch {
+    tiles: Grid<(Tile, bool)>,
+    start: Pos,
+}
+impl Sketch {
+    pub fn new(input: &str) -> Sketch {
+        let mut tiles = Grid::<Tile>::parse(input).map(|&tile| (tile, false));
+
+        let (start, _) = tiles.iter().find(|(_, (tile, _))| *tile == Tile::Start).unwrap();
+
+        let connected = Direction::ALL.into_iter()
+            .filter(|&direction| tiles.step(start, direction)
+                .is_some_and(|neighbour| tiles[neighbour].0.connects(direction.opposite()))
+            )
+            .collect::<Vec<Direction>>();
+        tiles[start].0 = Tile::joining(connected[0], connected[1]);
+
+        Sketch {
+            tiles,
+            start,
+        }
+    }
+
+    fn next_tile(&mut self, pos: Pos) -> Option<Pos> {
+        self.tiles[pos].1 = true;
+        let current = self.tiles[pos].0;
+
+        Direction::ALL.into_iter()
+            .filter(|&direction| current.connects(direction))
+            .filter_map(|direction| self.tiles.step(pos, direction))
+            .find(|&neighbour| !self.tiles[neighbour].1)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Tile {
+    NS,
+    EW,
+    NE,
+    NW,
+    SW,
+    SE,
+    Ground,
+    Start,
+}
+impl Tile {
+    fn connects(self, direction: Direction) -> bool {
+        matches!(
+            (self, direction),
+            (Tile::NS | Tile::NE | Tile::NW, Direction::North)
+                | (Tile::NS | Tile::SE | Tile::SW, Direction::South)
+                | (Tile::EW | Tile::NE | Tile::SE, Direction::East)
+                | (Tile::EW | Tile::NW | Tile::SW, Direction::West)
+        )
+    }
+
+    /// Returns the pipe that connects two directions.
+    fn joining(a: Direction, b: Direction) -> Tile {
+        [Tile::NS, Tile::EW, Tile::NE, Tile::NW, Tile::SW, Tile::SE].into_iter()
+            .find(|tile| tile.connects(a) && tile.connects(b))
+            .unwrap()
+    }
+}
+impl From<char> for Tile {
+    fn from(value: char) -> Tile {
+        match value {
+            '|' => Tile::NS,
+            '-' => Tile::EW,
+            'L' => Tile::NE,
+            'J' => Tile::NW,
+            '7' => Tile::SW,
+            'F' => Tile::SE,
+            '.' => Tile::Ground,
+            'S' => Tile::Start,
+            _ => panic!(),
+        }
+    }
+}
+
+pub struct Solver;
+impl Solution for Solver {
+    type Parsed<'a> = Sketch;
+
+    fn parse(input: &str) -> Sketch {
+        Sketch::new(input)
+    }
+
+    fn part_one(sketch: &Sketch) -> Answer {
+        solve_part_one(sketch).into()
+    }
+
+    fn part_two(sketch: &Sketch) -> Answer {
+        solve_part_two(sketch).into()
+    }
+}
+
+pub fn solve_part_one(sketch: &Sketch) -> usize {
+    let mut sketch = sketch.clone();
+
+    let mut current = sketch.next_tile(sketch.start).unwrap();
+    let mut moves: usize = 1;
+
+    while let Some(next) = sketch.next_tile(current) {
+        current = next;
+        moves += 1;
+    }
+    
+    moves.div_ceil(2)
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    Outside,
+    Inside,
+    EnteringN,
+    EnteringS,
+    ExitingN,
+    ExitingS,
+}
+
+pub fn solve_part_two(sketch: &Sketch) -> usize {
+    let mut sketch = sketch.clone();
+
+    let mut current = sketch.next_tile(sketch.start).unwrap();
+
+    while let Some(next) = sketch.next_tile(current) {
+        current = next;
+    }
+
+    let mut state = State::Outside;
+    let mut count = 0;
+
+    for row in sketch.tiles.rows() {
+        for &(tile, on_loop) in row {
+            let tile = if on_loop { tile } else { Tile::Ground };
+
+            match (state, tile) {
+                (State::Outside, Tile::NS) => state = State::Inside,
+                (State::Outside, Tile::NE) => state = State::EnteringN,
+                (State::Outside, Tile::SE) => state = State::EnteringS,
+                (State::Inside, Tile::NS) => state = State::Outside,
+                (State::Inside, Tile::NE) => state = State::ExitingN,
+                (State::Inside, Tile::SE) => state = State::ExitingS,
+                (State::Inside, Tile::Ground) => count += 1,
+                (State::EnteringN, Tile::NW) => state = State::Outside,
+                (State::EnteringN, Tile::SW) => state = State::Inside,
+                (State::EnteringS, Tile::NW) => state = State::Inside,
+                (State::EnteringS, Tile::SW) => state = State::Outside,
+                (State::ExitingN, Tile::NW) => state = State::Inside,
+                (State::ExitingN, Tile::SW) => state = State::Outside,
+                (State::ExitingS, Tile::NW) => state = State::Outside,
+                (State::ExitingS, Tile::SW) => state = State::Inside,
+                (_, _) => {},
+            }
+        }
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input::get_test_input, examples::example, solutions::Part};
+
+    #[test]
+    fn part_one_a() {
+        let input = get_test_input("2023", "day_10", Some("a")).unwrap();
+        let expected = 4;
+
+        assert_eq!(solve_part_one(&Solver::parse(&input)), expected);
+    }
+
+    #[test]
+    fn part_one_b() {
+        let (input, expected) = example("2023", "day_10", Part::One);
+
+        assert_eq!(solve_part_one(&Solver::parse(&input)).to_string(), expected);
+    }
+
+    #[test]
+    fn part_two_c() {
+        let input = get_test_input("2023", "day_10", Some("c")).unwrap();
+        let expected = 4;
+
+        assert_eq!(solve_part_two(&Solver::parse(&input)), expected);
+    }
+
+    #[test]
+    fn part_two_d() {
+        let input = get_test_input("2023", "day_10", Some("d")).unwrap();
+        let expected = 8;
+
+        assert_eq!(solve_part_two(&Solver::parse(&input)), expected);
+    }
+
+    #[test]
+    fn part_two_e() {
+        let (input, expected) = example("2023", "day_10", Part::Two);
+
+        assert_eq!(solve_part_two(&Solver::parse(&input)).to_string(), expected);
+    }
+}
@@ -0,0 +1,105 @@
+use itertools::Itertools;
+use std::collections::HashSet;
+
+use crate::{grid::{Grid, Pos}, solutions::{Answer, Solution}};
+
+pub struct Image {
+    galaxies: Vec<Pos>,
+    populated_rows: HashSet<usize>,
+    populated_cols: HashSet<usize>,
+}
+impl Image {
+    pub fn new(input: &str) -> Image {
+        let grid = Grid::<char>::parse(input);
+
+        let galaxies = grid.iter()
+            .filter(|(_, &c)| c == '#')
+            .map(|(pos, _)| pos)
+            .collect::<Vec<Pos>>();
+
+        let populated_rows = grid.rows()
+            .enumerate()
+            .filter_map(|(row, cells)| cells.contains(&'#').then_some(row))
+            .collect::<HashSet<usize>>();
+        let populated_cols = grid.columns()
+            .enumerate()
+            .filter_map(|(col, mut cells)| cells.any(|&c| c == '#').then_some(col))
+            .collect::<HashSet<usize>>();
+
+        Image {
+            galaxies,
+            populated_rows,
+            populated_cols,
+        }
+    }
+}
+
+pub struct Solver;
+impl Solution for Solver {
+    type Parsed<'a> = Image;
+
+    fn parse(input: &str) -> Image {
+        Image::new(input)
+    }
+
+    fn part_one(image: &Image) -> Answer {
+        solve_part_one(image).into()
+    }
+
+    fn part_two(image: &Image) -> Answer {
+        solve_part_two(image).into()
+    }
+}
+
+pub fn solve_part_one(image: &Image) -> usize {
+    sum_distances(image, 2)
+}
+
+pub fn solve_part_two(image: &Image) -> usize {
+    sum_distances(image, 1_000_000)
+}
+
+/// Counts the empty lines strictly between two others.
+fn empty_between(a: usize, b: usize, populated: &HashSet<usize>) -> usize {
+    (a.min(b) + 1..a.max(b)).filter(|line| !populated.contains(line)).count()
+}
+
+fn sum_distances(image: &Image, expansion: usize) -> usize {
+    image.galaxies.iter().tuple_combinations().map(|(a, b)| {
+        let distance = a.row.abs_diff(b.row) + a.col.abs_diff(b.col);
+
+        let empty_rows = empty_between(a.row, b.row, &image.populated_rows);
+        let empty_cols = empty_between(a.col, b.col, &image.populated_cols);
+
+        distance + (empty_rows + empty_cols) * (expansion - 1)
+    }).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{examples::example, solutions::Part};
+
+    #[test]
+    fn part_one() {
+        let (input, expected) = example("2023", "day_11", Part::One);
+
+        assert_eq!(solve_part_one(&Solver::parse(&input)).to_string(), expected);
+    }
+
+    #[test]
+    fn part_two_a() {
+        let (input, _) = example("2023", "day_11", Part::One);
+        let expected = 1030;
+
+        assert_eq!(sum_distances(&Solver::parse(&input), 10), expected);
+    }
+
+    #[test]
+    fn part_two_b() {
+        let (input, _) = example("2023", "day_11", Part::One);
+        let expected = 8410;
+
+        assert_eq!(sum_distances(&Solver::parse(&input), 100), expected);
+    }
+}
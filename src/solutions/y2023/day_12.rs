@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use crate::solutions::{Answer, Solution};
+
+const UNFOLD_FACTOR: usize = 5;
+
+pub struct Record {
+    states: Vec<State>,
+    groups: Vec<usize>,
+}
+impl Record {
+    pub fn new(line: &str) -> Record {
+        let (states, groups) = line.split_once(' ').unwrap();
+
+        let states = states.chars().map(|c| match c {
+            '.' => State::Working,
+            '#' => State::Broken,
+            '?' => State::Unknown,
+            _ => panic!(),
+        }).collect();
+
+        let groups = groups.split(',').map(|n| n.parse().unwrap()).collect();
+
+        Record {
+            states,
+            groups,
+        }
+    }
+
+    /// Repeats the record `factor` times, with an unknown spring between each copy.
+    pub fn unfold(&self, factor: usize) -> Record {
+        Record {
+            states: vec![self.states.clone(); factor].join(&State::Unknown),
+            groups: self.groups.repeat(factor),
+        }
+    }
+
+    /// Counts the ways of filling in the unknown springs that match the groups.
+    pub fn arrangements(&self) -> usize {
+        self.count(0, 0, 0, &mut HashMap::new())
+    }
+
+    /// Counts the arrangements of the states from `state` on, matching the groups from `group`
+    /// on, given the springs just before `state` end in a run of `run` broken ones.
+    fn count(&self, state: usize, group: usize, run: usize, memo: &mut HashMap<(usize, usize, usize), usize>) -> usize {
+        if let Some(&count) = memo.get(&(state, group, run)) {
+            return count;
+        }
+
+        let count = match self.states.get(state) {
+            None => match run {
+                0 => (group == self.groups.len()) as usize,
+                run => (group + 1 == self.groups.len() && self.groups[group] == run) as usize,
+            },
+            Some(current) => {
+                let mut count = 0;
+
+                if *current != State::Working && self.groups.get(group).is_some_and(|&size| run < size) {
+                    count += self.count(state + 1, group, run + 1, memo);
+                }
+
+                if *current != State::Broken {
+                    if run == 0 {
+                        count += self.count(state + 1, group, 0, memo);
+                    } else if self.groups[group] == run {
+                        count += self.count(state + 1, group + 1, 0, memo);
+                    }
+                }
+
+                count
+            },
+        };
+
+        memo.insert((state, group, run), count);
+        count
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum State {
+    Working,
+    Broken,
+    Unknown,
+}
+
+pub struct Solver;
+impl Solution for Solver {
+    type Parsed<'a> = Vec<Record>;
+
+    fn parse(input: &str) -> Vec<Record> {
+        input.lines().map(Record::new).collect()
+    }
+
+    fn part_one(records: &Vec<Record>) -> Answer {
+        solve_part_one(records).into()
+    }
+
+    fn part_two(records: &Vec<Record>) -> Answer {
+        solve_part_two(records).into()
+    }
+}
+
+pub fn solve_part_one(records: &[Record]) -> usize {
+    records.iter().map(Record::arrangements).sum()
+}
+
+pub fn solve_part_two(records: &[Record]) -> usize {
+    records.iter().map(|record| record.unfold(UNFOLD_FACTOR).arrangements()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{examples::example, solutions::Part};
+
+    #[test]
+    fn part_one() {
+        let (input, expected) = example("2023", "day_12", Part::One);
+
+        assert_eq!(solve_part_one(&Solver::parse(&input)).to_string(), expected);
+    }
+
+    #[test]
+    fn part_two() {
+        let (input, expected) = example("2023", "day_12", Part::Two);
+
+        assert_eq!(solve_part_two(&Solver::parse(&input)).to_string(), expected);
+    }
+
+    #[test]
+    fn arrangements() {
+        let (input, _) = example("2023", "day_12", Part::One);
+        let records = Solver::parse(&input);
+
+        let folded = records.iter().map(Record::arrangements).collect::<Vec<usize>>();
+        let unfolded = records.iter().map(|record| record.unfold(5).arrangements()).collect::<Vec<usize>>();
+
+        assert_eq!(folded, [1, 4, 1, 1, 4, 10]);
+        assert_eq!(unfolded, [1, 16384, 1, 16, 2500, 506250]);
+    }
+
+    #[test]
+    fn unfold() {
+        let record = Record::new(".# 1");
+        let unfolded = record.unfold(3);
+
+        assert_eq!(unfolded.states, Record::new(".#?.#?.# 1").states);
+        assert_eq!(unfolded.groups, [1, 1, 1]);
+        assert_eq!(record.unfold(1).states, record.states);
+    }
+}
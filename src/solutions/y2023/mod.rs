@@ -0,0 +1,16 @@
+pub const YEAR: &str = "2023";
+
+days!(
+    day_01,
+    day_02,
+    day_03,
+    day_04,
+    day_05,
+    day_06,
+    day_07,
+    day_08,
+    day_09,
+    day_10,
+    day_11,
+    day_12,
+);
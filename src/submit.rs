@@ -0,0 +1,217 @@
+use std::{fmt, fs::{create_dir_all, read_to_string, OpenOptions}, io::Write, path::PathBuf};
+
+use crate::{answers, config, input::Http, markdown::strip_tags, solutions::{Answer, Part}};
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited(String),
+    AlreadySolved,
+    Unknown(String),
+}
+impl Verdict {
+    fn parse(html: &str) -> Verdict {
+        let text = match (html.find("<article"), html.find("</article>")) {
+            (Some(start), Some(end)) => &html[start..end],
+            _ => html,
+        };
+
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if text.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            let wait = text.split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string())
+                .unwrap_or_default();
+            Verdict::RateLimited(wait)
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown(strip_tags(text).split_whitespace().collect::<Vec<&str>>().join(" "))
+        }
+    }
+}
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(wait) if wait.is_empty() => write!(f, "rate limited"),
+            Verdict::RateLimited(wait) => write!(f, "rate limited, {wait} left to wait"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::Unknown(text) => write!(f, "unrecognised response: {text}"),
+        }
+    }
+}
+
+struct Guess {
+    part: Part,
+    answer: String,
+    verdict: Verdict,
+}
+
+fn guesses_path(year: &str, day: &str) -> PathBuf {
+    config::get().path("answers").join(year).join(format!("{day}.guesses.txt"))
+}
+
+fn load_guesses(year: &str, day: &str) -> Vec<Guess> {
+    read_to_string(guesses_path(year, day)).unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            let (part, rest) = line.split_once(": ")?;
+            let (answer, verdict) = rest.rsplit_once(": ")?;
+
+            let verdict = match verdict {
+                "too high" => Verdict::TooHigh,
+                "too low" => Verdict::TooLow,
+                _ => Verdict::Wrong,
+            };
+
+            Some(Guess {
+                part: part.parse().ok()?,
+                answer: answer.to_string(),
+                verdict,
+            })
+        })
+        .collect()
+}
+
+fn save_guess(year: &str, day: &str, part: Part, answer: &Answer, verdict: &Verdict) {
+    let path = guesses_path(year, day);
+    create_dir_all(path.parent().unwrap()).unwrap();
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .unwrap();
+
+    writeln!(file, "{part}: {answer}: {verdict}").unwrap();
+}
+
+fn as_number(answer: &str) -> Option<i128> {
+    answer.parse().ok()
+}
+
+/// Returns the reason an answer is already known to be wrong, if any.
+fn rule_out(guesses: &[Guess], part: Part, answer: &Answer) -> Option<String> {
+    let answer = answer.to_string();
+    let value = as_number(&answer);
+
+    guesses.iter()
+        .filter(|guess| guess.part == part)
+        .find_map(|guess| {
+            if guess.answer == answer {
+                return Some(format!("{answer} was already guessed and was {}", guess.verdict));
+            }
+
+            match (&guess.verdict, value, as_number(&guess.answer)) {
+                (Verdict::TooHigh, Some(value), Some(bound)) if value >= bound => {
+                    Some(format!("{answer} is not below {bound}, which was too high"))
+                },
+                (Verdict::TooLow, Some(value), Some(bound)) if value <= bound => {
+                    Some(format!("{answer} is not above {bound}, which was too low"))
+                },
+                _ => None,
+            }
+        })
+}
+
+/// Submits an answer unless it has been ruled out by a previous guess, remembering wrong
+/// guesses and recording correct answers.
+pub fn submit(http: &impl Http, base_url: &str, year: &str, day: &str, part: Part, answer: &Answer) -> Result<Verdict, String> {
+    if let Some(reason) = rule_out(&load_guesses(year, day), part, answer) {
+        return Err(reason);
+    }
+
+    let number = day.trim_start_matches("day_").trim_start_matches('0');
+    let url = format!("{base_url}/{year}/day/{number}/answer");
+    let level = part.to_string();
+    let answer_text = answer.to_string();
+
+    let html = http.post(&url, &[("level", &level), ("answer", &answer_text)])
+        .map_err(|error| error.to_string())?;
+    let verdict = Verdict::parse(&html);
+
+    match verdict {
+        Verdict::Correct => answers::record(year, day, part, answer),
+        Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong => save_guess(year, day, part, answer, &verdict),
+        _ => {},
+    }
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{MockServer, Route};
+    use reqwest::blocking::Client;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn parse_verdicts() {
+        let cases = [
+            ("That's the right answer! You are one gold star closer.", Verdict::Correct),
+            ("That's not the right answer; your answer is too high.", Verdict::TooHigh),
+            ("That's not the right answer; your answer is too low.", Verdict::TooLow),
+            ("That's not the right answer. If you're stuck, ...", Verdict::Wrong),
+            (
+                "You gave an answer too recently. You have 42s left to wait.",
+                Verdict::RateLimited(String::from("42s")),
+            ),
+            ("You don't seem to be solving the right level.", Verdict::AlreadySolved),
+        ];
+
+        for (message, expected) in cases {
+            assert_eq!(Verdict::parse(&page(message)), expected);
+        }
+    }
+
+    #[test]
+    fn rule_out_guesses() {
+        let guesses = vec![
+            Guess { part: Part::One, answer: String::from("100"), verdict: Verdict::TooHigh },
+            Guess { part: Part::One, answer: String::from("10"), verdict: Verdict::TooLow },
+            Guess { part: Part::Two, answer: String::from("abc"), verdict: Verdict::Wrong },
+        ];
+
+        assert!(rule_out(&guesses, Part::One, &Answer::Unsigned(100)).is_some());
+        assert!(rule_out(&guesses, Part::One, &Answer::Unsigned(150)).is_some());
+        assert!(rule_out(&guesses, Part::One, &Answer::Signed(-3)).is_some());
+        assert!(rule_out(&guesses, Part::One, &Answer::Unsigned(50)).is_none());
+        assert!(rule_out(&guesses, Part::Two, &Answer::from("abc")).is_some());
+        assert!(rule_out(&guesses, Part::Two, &Answer::Unsigned(100)).is_none());
+    }
+
+    #[test]
+    fn post_to_mock_server() {
+        let server = MockServer::start(vec![
+            Route::post("/2023/day/7/answer", 200, &page("You gave an answer too recently. You have 1m 5s left to wait.")),
+        ]);
+
+        let verdict = submit(&Client::new(), &server.url, "2023", "day_07", Part::Two, &Answer::Unsigned(1234));
+        let requests = server.requests();
+
+        assert_eq!(verdict, Ok(Verdict::RateLimited(String::from("1m 5s"))));
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path, "/2023/day/7/answer");
+        assert_eq!(requests[0].body, "level=2&answer=1234");
+    }
+}
@@ -0,0 +1,168 @@
+use std::{
+    env,
+    fs::{metadata, read_dir},
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{config::{self, Config}, solutions::Day};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The files that affect a day: its solution and any of its inputs.
+fn watched_files(day: &Day) -> Vec<PathBuf> {
+    let config = config::get();
+
+    let mut files = vec![config.path("src/solutions").join(format!("y{}/{}.rs", day.year, day.name))];
+
+    if let Ok(entries) = read_dir(config.input_dir.join(day.year)) {
+        let mut inputs = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.file_name().and_then(|name| name.to_str()).is_some_and(|name| {
+                name.starts_with(day.name) && name.ends_with(".txt")
+            }))
+            .collect::<Vec<_>>();
+        inputs.sort();
+        files.extend(inputs);
+    }
+
+    files
+}
+
+fn snapshot(day: &Day) -> Vec<(PathBuf, Option<SystemTime>)> {
+    watched_files(day).into_iter()
+        .map(|path| {
+            let modified = metadata(&path).and_then(|metadata| metadata.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+fn cargo() -> Command {
+    let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| String::from("cargo")));
+    command.current_dir(&config::get().root);
+    command
+}
+
+/// Passes on the settings given to `watch`, so each run reads the same inputs and cache.
+fn config_args(config: &Config) -> Vec<String> {
+    let mut args = vec![
+        String::from("--input-dir"),
+        config.input_dir.display().to_string(),
+        String::from("--cache-dir"),
+        config.cache_dir.display().to_string(),
+        String::from("--user-agent"),
+        config.user_agent.clone(),
+        String::from("--base-url"),
+        config.base_url.clone(),
+    ];
+
+    if config.offline {
+        args.push(String::from("--offline"));
+    }
+
+    args
+}
+
+/// Runs one part with the freshly built binary, returning its answer or why there isn't one.
+fn run_part(day: &Day, part: usize) -> Result<String, String> {
+    let output = cargo()
+        .args(["run", "--quiet", "--", "--format", "text"])
+        .args(["--year", day.year, "--day", &day.number().to_string(), "--part", &part.to_string()])
+        .args(config_args(config::get()))
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|error| error.to_string())?;
+
+    match output.status.success() {
+        true => Ok(String::from_utf8_lossy(&output.stdout).trim().to_string()),
+        false => Err(String::from("failed")),
+    }
+}
+
+/// Describes how an answer changed since the last run.
+fn describe(previous: Option<&Result<String, String>>, current: &Result<String, String>) -> String {
+    match (previous, current) {
+        (_, Err(error)) => error.clone(),
+        (Some(Ok(previous)), Ok(current)) if previous == current => format!("{current} (unchanged)"),
+        (Some(Ok(previous)), Ok(current)) => format!("{current} (was {previous})"),
+        (_, Ok(current)) => format!("{current} (new)"),
+    }
+}
+
+fn rebuild_and_run(day: &Day, previous: &mut [Option<Result<String, String>>; 2]) {
+    println!("\n--- {} {} ---", day.year, day.name);
+
+    if !cargo().args(["build", "--quiet"]).status().is_ok_and(|status| status.success()) {
+        return println!("build failed");
+    }
+
+    let filter = format!("y{}::{}::", day.year, day.name);
+    if !cargo().args(["test", "--quiet", &filter]).status().is_ok_and(|status| status.success()) {
+        println!("tests failed");
+    }
+
+    for (index, previous) in previous.iter_mut().enumerate() {
+        let current = run_part(day, index + 1);
+        println!("part {}: {}", index + 1, describe(previous.as_ref(), &current));
+        *previous = Some(current);
+    }
+}
+
+/// Rebuilds, tests and runs a day whenever its solution or inputs change.
+pub fn watch(day: &Day) {
+    let mut previous = [None, None];
+    let mut files = snapshot(day);
+
+    println!("watching {} file(s), press ctrl-c to stop", files.len());
+    rebuild_and_run(day, &mut previous);
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let current = snapshot(day);
+        if current != files {
+            // give editors a moment to finish writing before building
+            thread::sleep(POLL_INTERVAL);
+            files = snapshot(day);
+            rebuild_and_run(day, &mut previous);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describe_changes() {
+        let answer = |value: &str| Ok(String::from(value));
+
+        assert_eq!(describe(None, &answer("142")), "142 (new)");
+        assert_eq!(describe(Some(&answer("142")), &answer("142")), "142 (unchanged)");
+        assert_eq!(describe(Some(&answer("140")), &answer("142")), "142 (was 140)");
+        assert_eq!(describe(Some(&Err(String::from("failed"))), &answer("142")), "142 (new)");
+        assert_eq!(describe(Some(&answer("142")), &Err(String::from("failed"))), "failed");
+    }
+
+    #[test]
+    fn forward_config() {
+        let file = config::FileConfig {
+            input_dir: Some(PathBuf::from("puzzle-inputs")),
+            base_url: Some(String::from("http://localhost:8000/")),
+            ..config::FileConfig::default()
+        };
+        let cli = config::Overrides {
+            offline: true,
+            ..config::Overrides::default()
+        };
+        let config = Config::resolve(PathBuf::from("/project"), file, |_| None, cli).unwrap();
+
+        let args = config_args(&config);
+
+        assert_eq!(args[..4], ["--input-dir", "/project/puzzle-inputs", "--cache-dir", "/project/.cache"]);
+        assert_eq!(args[6..], ["--base-url", "http://localhost:8000", "--offline"]);
+    }
+}
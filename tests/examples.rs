@@ -0,0 +1,12 @@
+use advent_of_code_2023::{examples::load, solutions::YEARS};
+
+#[test]
+fn saved_examples() {
+    for day in YEARS.iter().flat_map(|days| days.iter()) {
+        for (part, input, expected) in load(day.year, day.name) {
+            let answer = (day.run)(&input, part).answer;
+
+            assert_eq!(answer.to_string(), expected, "{} {} part {part}", day.year, day.name);
+        }
+    }
+}
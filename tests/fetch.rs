@@ -0,0 +1,145 @@
+use advent_of_code_2023::{
+    config::{Config, FileConfig, Overrides},
+    input::{load_input, load_puzzle_page, InputError},
+    mock::{MockServer, Route},
+};
+use std::{env, fs::{create_dir_all, read_to_string, remove_dir_all, write}, process};
+
+const PAGE: &str = include_str!("../fixtures/puzzle.html");
+
+/// Returns a config rooted in a fresh temporary directory that fetches from the server.
+fn test_config(name: &str, server: &MockServer, offline: bool) -> Config {
+    let root = env::temp_dir().join(format!("aoc-{name}-{}", process::id()));
+    remove_dir_all(&root).ok();
+
+    let file = FileConfig {
+        session_cookie: Some(String::from("abc")),
+        base_url: Some(server.url.clone()),
+        offline: Some(offline),
+        request_interval: Some(0),
+        ..FileConfig::default()
+    };
+
+    Config::resolve(root, file, |_| None, Overrides::default()).unwrap()
+}
+
+#[test]
+fn fetch_and_cache_input() {
+    let server = MockServer::start(vec![Route::get("/2023/day/1/input", 200, "1abc2\n")]);
+    let config = test_config("fetch-input", &server, false);
+
+    assert_eq!(load_input(&config, "2023", "day_01").unwrap(), "1abc2\n");
+    assert_eq!(load_input(&config, "2023", "day_01").unwrap(), "1abc2\n");
+    assert_eq!(server.requests().len(), 1);
+    assert!(config.input_dir.join("2023/day_01.txt").is_file());
+
+    let log = read_to_string(config.cache_dir.join("requests.log")).unwrap();
+    let fields = log.split_whitespace().collect::<Vec<&str>>();
+    assert_eq!(fields[1..], ["GET", &format!("{}/2023/day/1/input", server.url), "200"]);
+}
+
+#[test]
+fn honour_retry_after() {
+    let server = MockServer::start(vec![
+        Route::get("/2023/day/1/input", 429, "slow down").header("Retry-After", "3600"),
+    ]);
+    let config = test_config("retry-after", &server, false);
+
+    assert!(matches!(load_input(&config, "2023", "day_01"), Err(InputError::Status(429))));
+    assert!(matches!(load_input(&config, "2023", "day_01"), Err(InputError::RetryAfter(_))));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn back_off_on_dated_or_unknown_retry_after() {
+    let server = MockServer::start(vec![
+        Route::get("/2023/day/1/input", 503, "down").header("Retry-After", "Fri, 31 Dec 9999 23:59:59 GMT"),
+        Route::get("/2023/day/2/input", 503, "down").header("Retry-After", "later"),
+    ]);
+
+    for day in ["day_01", "day_02"] {
+        let config = test_config(&format!("retry-after-{day}"), &server, false);
+
+        assert!(matches!(load_input(&config, "2023", day), Err(InputError::Status(503))));
+        assert!(matches!(load_input(&config, "2023", day), Err(InputError::RetryAfter(_))));
+    }
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn log_every_request() {
+    let server = MockServer::start(vec![
+        Route::get("/2023/day/1/input", 200, "1abc2\n"),
+        Route::get("/2023/day/2/input", 200, "Game 1: 3 blue\n"),
+    ]);
+    let config = test_config("trim-log", &server, false);
+
+    load_input(&config, "2023", "day_01").unwrap();
+    load_input(&config, "2023", "day_02").unwrap();
+
+    let log = read_to_string(config.cache_dir.join("requests.log")).unwrap();
+    let lines = log.lines().collect::<Vec<&str>>();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].contains("/2023/day/1/input 200"));
+    assert!(lines[1].contains("/2023/day/2/input 200"));
+}
+
+#[test]
+fn offline_without_cache() {
+    let server = MockServer::start(vec![Route::get("/2023/day/1/input", 200, "1abc2\n")]);
+    let config = test_config("offline", &server, true);
+
+    assert!(matches!(load_input(&config, "2023", "day_01"), Err(InputError::Offline(_))));
+    assert!(matches!(load_puzzle_page(&config, "2023", "day_01"), Err(InputError::Offline(_))));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn fetch_errors() {
+    let server = MockServer::start(vec![
+        Route::get("/2023/day/1/input", 404, "Please don't repeatedly request this endpoint before it unlocks!"),
+        Route::get("/2023/day/2/input", 200, "<!DOCTYPE html>\n<a href=\"/2023/auth/login\">[Log In]</a>"),
+    ]);
+    let config = test_config("fetch-errors", &server, false);
+
+    assert!(matches!(load_input(&config, "2023", "day_01"), Err(InputError::Locked)));
+    assert!(matches!(load_input(&config, "2023", "day_02"), Err(InputError::LoggedOut)));
+    assert!(matches!(load_input(&config, "2023", "day_03"), Err(InputError::Status(404))));
+    assert!(!config.input_dir.exists());
+}
+
+#[test]
+fn fetch_and_cache_puzzle_page() {
+    let server = MockServer::start(vec![Route::get("/2023/day/9", 200, PAGE)]);
+    let config = test_config("fetch-page", &server, false);
+
+    assert_eq!(load_puzzle_page(&config, "2023", "day_09").unwrap(), PAGE);
+    assert_eq!(load_puzzle_page(&config, "2023", "day_09").unwrap(), PAGE);
+    assert_eq!(server.requests().len(), 1);
+    assert_eq!(server.requests()[0].method, "GET");
+}
+
+#[test]
+fn refuse_to_cache_bad_pages() {
+    let server = MockServer::start(vec![
+        Route::get("/2023/day/1", 200, "<!DOCTYPE html>\n<a href=\"/2023/auth/login\">[Log In]</a>"),
+        Route::get("/2023/day/2", 200, "<!DOCTYPE html>\n<main>nothing here</main>"),
+    ]);
+    let config = test_config("bad-pages", &server, false);
+
+    assert!(matches!(load_puzzle_page(&config, "2023", "day_01"), Err(InputError::LoggedOut)));
+    assert!(matches!(load_puzzle_page(&config, "2023", "day_02"), Err(InputError::NoPuzzle)));
+    assert!(!config.cache_dir.join("2023").exists());
+}
+
+#[test]
+fn ignore_bad_cached_pages_offline() {
+    let server = MockServer::start(Vec::new());
+    let config = test_config("bad-cache", &server, true);
+
+    create_dir_all(config.cache_dir.join("2023")).unwrap();
+    write(config.cache_dir.join("2023/day_01.html"), "<main>nothing here</main>").unwrap();
+
+    assert!(matches!(load_puzzle_page(&config, "2023", "day_01"), Err(InputError::Offline(_))));
+    assert!(server.requests().is_empty());
+}
@@ -0,0 +1,29 @@
+use advent_of_code_2023::solutions::{get_day, get_days, latest_year, y2023::day_05::{solve_part_one, Almanac}, YEARS};
+
+#[test]
+fn days_are_registered_in_order() {
+    for days in YEARS {
+        let numbers = days.iter().map(|day| day.number()).collect::<Vec<_>>();
+
+        assert!(!numbers.is_empty());
+        assert!(numbers.windows(2).all(|pair| pair[0] < pair[1]), "{numbers:?}");
+        assert!(days.iter().all(|day| day.year == days[0].year));
+    }
+}
+
+#[test]
+fn look_up_days() {
+    let year = latest_year();
+
+    assert!(get_days(year).unwrap().iter().all(|day| day.year == year));
+    assert_eq!(get_day("2023", 5).unwrap().name, "day_05");
+    assert!(get_day("2023", 26).is_none());
+    assert!(get_days("1999").is_none());
+}
+
+#[test]
+fn parsers_are_public() {
+    let input = "seeds: 79 14\n\nseed-to-location map:\n50 98 2\n52 50 48\n";
+
+    assert_eq!(solve_part_one(&Almanac::new(input)), 14);
+}